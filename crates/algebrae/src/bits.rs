@@ -0,0 +1,61 @@
+/// Returns the `n`-th binary reflected Gray code, where consecutive codes differ in one bit.
+///
+/// # Complexity
+/// Time: O(1)
+#[inline(always)]
+pub fn gray_code(n: u32) -> u32 {
+    n ^ (n >> 1)
+}
+
+/// Returns `n` such that `gray_code(n) == g`.
+///
+/// # Complexity
+/// Time: O(log bits)
+#[inline(always)]
+pub fn inverse_gray(mut g: u32) -> u32 {
+    g ^= g >> 16;
+    g ^= g >> 8;
+    g ^= g >> 4;
+    g ^= g >> 2;
+    g ^= g >> 1;
+    g
+}
+
+/// Returns an iterator over all masks of `k` bits chosen from the lowest `n` bits, in increasing
+/// order, by Gosper's hack.
+///
+/// # Complexity
+/// Time: O(1) per item
+pub fn subsets_of_size(n: u32, k: u32) -> SubsetsOfSize {
+    debug_assert!(n < 64, "n must be less than 64: n={}", n);
+    SubsetsOfSize {
+        cur: if k <= n { (1u64 << k) - 1 } else { 1 << n },
+        limit: 1 << n,
+    }
+}
+
+/// An iterator over `k`-subsets created by [`subsets_of_size`].
+#[derive(Clone)]
+pub struct SubsetsOfSize {
+    cur: u64,
+    limit: u64,
+}
+
+impl Iterator for SubsetsOfSize {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let x = self.cur;
+        if x >= self.limit {
+            return None;
+        }
+        self.cur = if x == 0 {
+            self.limit
+        } else {
+            let c = x & x.wrapping_neg();
+            let r = x + c;
+            (((r ^ x) >> 2) / c) | r
+        };
+        Some(x)
+    }
+}
@@ -1,4 +1,6 @@
 pub mod algebra;
+pub mod bits;
 pub mod conv;
 pub mod linear;
 pub mod num_theory;
+pub mod poly;
@@ -0,0 +1,123 @@
+use crate::{algebra::Field, linear::Matrix};
+
+/// Calculates the determinant of the `n` by `n` matrix whose nonzero entries are given as
+/// `(row, col, value)` triples, with duplicates summed. Rows are kept as sorted sparse lists and
+/// eliminated column by column, taking the shortest candidate as the pivot to limit fill-in. Once
+/// the remaining rows are at least half full they are handed to the dense `Matrix::det`.
+///
+/// # Complexity
+/// Time: O(n^3) in the worst case, much less when little fill-in occurs.
+pub fn det_sparse<T: PartialEq + Field>(n: usize, entries: &[(usize, usize, T)]) -> T {
+    let mut rows: Vec<Vec<(usize, T)>> = vec![Vec::new(); n];
+    for &(i, j, x) in entries {
+        debug_assert!(
+            i < n && j < n,
+            "index out of bounds: row={}, col={}, n={}",
+            i,
+            j,
+            n,
+        );
+        rows[i].push((j, x));
+    }
+    let mut nnz = 0;
+    for row in rows.iter_mut() {
+        row.sort_unstable_by_key(|&(j, _)| j);
+        let mut merged: Vec<(usize, T)> = Vec::with_capacity(row.len());
+        for &(j, x) in row.iter() {
+            match merged.last_mut() {
+                Some(last) if last.0 == j => last.1 = last.1 + x,
+                _ => merged.push((j, x)),
+            }
+        }
+        merged.retain(|&(_, x)| x != T::zero());
+        nnz += merged.len();
+        *row = merged;
+    }
+
+    // Rows not yet used as pivots, grouped by their leading column.
+    let mut bucket: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (i, row) in rows.iter().enumerate() {
+        match row.first() {
+            Some(&(j, _)) => bucket[j].push(i),
+            None => return T::zero(),
+        }
+    }
+    let mut order = Vec::with_capacity(n);
+    let mut used = vec![false; n];
+    let mut res = T::one();
+    for c in 0..n {
+        let m = n - c;
+        if 2 * nnz >= m * m && m > 1 {
+            // Hands the rest to the dense determinant, with rows in ascending original order.
+            let rest: Vec<usize> = (0..n).filter(|&i| !used[i]).collect();
+            let mut dense = Matrix::zero(m, m);
+            for (k, &i) in rest.iter().enumerate() {
+                for &(j, x) in rows[i].iter() {
+                    dense[k][j - c] = x;
+                }
+            }
+            order.extend(rest);
+            res = res * dense.det();
+            break;
+        }
+        let candidates = std::mem::take(&mut bucket[c]);
+        let Some(&p) = candidates.iter().min_by_key(|&&i| rows[i].len()) else {
+            return T::zero();
+        };
+        used[p] = true;
+        order.push(p);
+        nnz -= rows[p].len();
+        let pivot = std::mem::take(&mut rows[p]);
+        let inv = T::one() / pivot[0].1;
+        res = res * pivot[0].1;
+        for &i in candidates.iter().filter(|&&i| i != p) {
+            let factor = rows[i][0].1 * inv;
+            let row = std::mem::take(&mut rows[i]);
+            nnz -= row.len();
+            // Merges `row - factor * pivot`, both sorted by column, dropping the leading entry.
+            let mut merged = Vec::with_capacity(row.len() + pivot.len());
+            let (mut a, mut b) = (1, 1);
+            while a < row.len() || b < pivot.len() {
+                if b == pivot.len() || (a < row.len() && row[a].0 < pivot[b].0) {
+                    merged.push(row[a]);
+                    a += 1;
+                } else if a == row.len() || pivot[b].0 < row[a].0 {
+                    merged.push((pivot[b].0, -(factor * pivot[b].1)));
+                    b += 1;
+                } else {
+                    let x = row[a].1 - factor * pivot[b].1;
+                    if x != T::zero() {
+                        merged.push((row[a].0, x));
+                    }
+                    a += 1;
+                    b += 1;
+                }
+            }
+            match merged.first() {
+                Some(&(j, _)) => bucket[j].push(i),
+                None => return T::zero(),
+            }
+            nnz += merged.len();
+            rows[i] = merged;
+        }
+    }
+
+    // Multiplies by the sign of the row permutation `order`.
+    let mut seen = vec![false; n];
+    for s in 0..n {
+        if seen[s] {
+            continue;
+        }
+        let mut len = 0;
+        let mut v = s;
+        while !seen[v] {
+            seen[v] = true;
+            v = order[v];
+            len += 1;
+        }
+        if len % 2 == 0 {
+            res = -res;
+        }
+    }
+    res
+}
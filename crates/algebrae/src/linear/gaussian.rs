@@ -126,14 +126,32 @@ impl<T: PartialEq + Field> Matrix<T> {
         }
     }
 
+    /// Calculates rank of matrix and the pivot columns of its row echelon form, in increasing
+    /// order. The pivot columns of the original matrix form a basis of its column space, and the
+    /// other columns correspond to free variables.
+    ///
+    /// # Complexity
+    /// Time: O(hw min(h, w))
+    pub fn rank_with_pivots(&self) -> (usize, Vec<usize>) {
+        let mut a = self.clone();
+        let pivots = a.row_reduce_pivots();
+        (pivots.len(), pivots)
+    }
+
     /// Reduces the matrix to row echelon form and returns the rank.
     ///
     /// # Complexity
     /// Time: O(hw min(h, w))
     pub fn row_reduce(&mut self) -> usize {
+        self.row_reduce_pivots().len()
+    }
+
+    /// Reduces the matrix to row echelon form and returns the pivot columns.
+    fn row_reduce_pivots(&mut self) -> Vec<usize> {
         let h = self.h();
         let w = self.w();
         let mut rank = 0;
+        let mut pivots = Vec::with_capacity(h.min(w));
         unsafe {
             let ptr = self.data.as_mut_ptr();
             for col in 0..w {
@@ -165,10 +183,11 @@ impl<T: PartialEq + Field> Matrix<T> {
                         *ptr.add(row * w + j) = *ptr.add(row * w + j) - p * *ptr.add(rank * w + j);
                     }
                 }
+                pivots.push(col);
                 rank += 1;
             }
         }
-        rank
+        pivots
     }
 
     /// Reduces the matrix to reduced row echelon form and returns the rank (usize).
@@ -0,0 +1,20 @@
+use crate::{
+    algebra::Field,
+    linear::{Matrix, linear_system},
+};
+
+/// Solves the least squares problem min |ax - b| exactly through the normal equations
+/// a^T a x = a^T b, which is meant for exact fields such as `Rational`. Returns `None` if a^T a
+/// is singular, that is when the columns of `a` are linearly dependent.
+///
+/// # Complexity
+/// Time: O(hw^2 + w^3)
+pub fn least_squares<T: PartialEq + Field>(a: &Matrix<T>, b: &[T]) -> Option<Vec<T>> {
+    debug_assert_eq!(a.h(), b.len(), "dimension mismatch");
+    let at = a.transpose();
+    let b = Matrix::from_flat(b.len(), 1, b.to_vec());
+    let atb = at.clone() * b;
+    let ata = at * a.clone();
+    let (x, kernel) = linear_system(&ata, &atb.data)?;
+    (kernel.h() == 0).then_some(x)
+}
@@ -105,6 +105,27 @@ impl<T: Copy> Matrix<T> {
         self.data.chunks_exact_mut(self.w())
     }
 
+    /// Returns the transposed matrix.
+    ///
+    /// # Complexity
+    /// Time: O(hw)
+    pub fn transpose(&self) -> Self {
+        let h = self.h();
+        let w = self.w();
+        let mut data: Vec<T> = Vec::with_capacity(h * w);
+        unsafe {
+            let src = self.data.as_ptr();
+            let dst = data.as_mut_ptr();
+            for i in 0..h {
+                for j in 0..w {
+                    dst.add(j * h + i).write(*src.add(i * w + j));
+                }
+            }
+            data.set_len(h * w);
+        }
+        Self::from_flat(w, h, data)
+    }
+
     /// Returns whether the matrix is square.
     ///
     /// # Complexity
@@ -168,6 +189,70 @@ impl<T: Rig> Matrix<T> {
     }
 }
 
+impl Matrix<u64> {
+    /// Computes multiple of matrices modulo `m` with `u128` intermediates, where `m` may be
+    /// composite. Entries are assumed to be less than `m`.
+    ///
+    /// # Complexity
+    /// Time: O(hwd)
+    pub fn mul_mod(&self, rhs: &Self, m: u64) -> Self {
+        debug_assert_eq!(self.w, rhs.h, "Shape is mismatch");
+        debug_assert!(m > 0, "m must not be zero");
+        let h = self.h();
+        let w = rhs.w();
+        let d = self.w();
+        let m = m as u128;
+        let mut res = vec![0; h * w];
+        unsafe {
+            let res = res.as_mut_ptr();
+            let lhs = self.data.as_ptr();
+            let rhs = rhs.data.as_ptr();
+            for i in 0..h {
+                for j in 0..d {
+                    let a = *lhs.add(i * d + j) as u128;
+                    for k in 0..w {
+                        let r = res.add(i * w + k);
+                        *r = ((*r as u128 + a * *rhs.add(j * w + k) as u128) % m) as u64;
+                    }
+                }
+            }
+        }
+        Self {
+            h,
+            w,
+            data: res.into_boxed_slice(),
+        }
+    }
+
+    /// Computes pow of matrix modulo `m`, where `m` may be composite. Entries are assumed to be
+    /// less than `m`.
+    ///
+    /// # Complexity
+    /// Time: O(n^3 log exp)
+    pub fn pow_mod(&self, mut exp: u64, m: u64) -> Self {
+        debug_assert!(self.is_square(), "Matrix must be square");
+        debug_assert!(m > 0, "m must not be zero");
+        let n = self.h();
+        let mut base = self.clone();
+        let mut data = vec![0; n * n];
+        unsafe {
+            let data = data.as_mut_ptr();
+            for i in 0..n {
+                *data.add((n + 1) * i) = 1 % m;
+            }
+        }
+        let mut res = Self::from_flat(n, n, data);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = res.mul_mod(&base, m);
+            }
+            base = base.mul_mod(&base, m);
+            exp >>= 1;
+        }
+        res
+    }
+}
+
 impl<T: Copy> std::ops::Index<usize> for Matrix<T> {
     type Output = [T];
     #[inline(always)]
@@ -1,6 +1,12 @@
+mod det_sparse;
 mod gaussian;
+mod least_squares;
 mod linear_system;
 mod matrix;
+mod xor_basis;
 
+pub use det_sparse::det_sparse;
+pub use least_squares::least_squares;
 pub use linear_system::linear_system;
 pub use matrix::Matrix;
+pub use xor_basis::XorBasis;
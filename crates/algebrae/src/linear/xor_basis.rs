@@ -0,0 +1,112 @@
+/// A linear basis of 64-bit vectors over GF(2), kept in reduced row echelon form so that every
+/// leading bit appears in exactly one basis vector.
+///
+/// # Complexity
+/// Space: O(64)
+#[derive(Clone)]
+pub struct XorBasis {
+    basis: [u64; 64],
+    rank: usize,
+}
+
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XorBasis {
+    /// Creates an empty basis spanning only `0`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new() -> Self {
+        Self {
+            basis: [0; 64],
+            rank: 0,
+        }
+    }
+
+    /// Inserts `x` and returns whether it was independent of the current basis.
+    ///
+    /// # Complexity
+    /// Time: O(64)
+    pub fn insert(&mut self, x: u64) -> bool {
+        let x = self.reduce(x);
+        if x == 0 {
+            return false;
+        }
+        let top = 63 - x.leading_zeros() as usize;
+        for b in self.basis.iter_mut() {
+            if *b >> top & 1 == 1 {
+                *b ^= x;
+            }
+        }
+        self.basis[top] = x;
+        self.rank += 1;
+        true
+    }
+
+    /// Returns whether `x` is in the span of the basis.
+    ///
+    /// # Complexity
+    /// Time: O(64)
+    pub fn contains(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+
+    /// Returns the maximum XOR of any subset of inserted values.
+    ///
+    /// # Complexity
+    /// Time: O(64)
+    pub fn max_xor(&self) -> u64 {
+        self.basis.iter().fold(0, |acc, &b| acc ^ b)
+    }
+
+    /// Returns the `k`-th smallest (0-indexed) value in the span, where `k = 0` gives `0`. If
+    /// `k >= 2^rank` returns `None`.
+    ///
+    /// # Complexity
+    /// Time: O(64)
+    pub fn kth_xor(&self, mut k: u64) -> Option<u64> {
+        if self.rank < 64 && k >> self.rank != 0 {
+            return None;
+        }
+        let mut res = 0;
+        for &b in self.basis.iter() {
+            if b != 0 {
+                if k & 1 == 1 {
+                    res ^= b;
+                }
+                k >>= 1;
+            }
+        }
+        Some(res)
+    }
+
+    /// Returns the number of basis vectors.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns the number of values in the span, that is `2^rank`. If `rank = 64` returns `None`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn size(&self) -> Option<u64> {
+        1u64.checked_shl(self.rank as u32)
+    }
+
+    fn reduce(&self, mut x: u64) -> u64 {
+        for i in (0..64).rev() {
+            if x >> i & 1 == 1 {
+                x ^= self.basis[i];
+            }
+        }
+        x
+    }
+}
@@ -0,0 +1,94 @@
+use crate::num_theory::{
+    factorize,
+    pow_mod::{inv_mod, mul_mod, sub_mod},
+};
+
+/// Returns `C(n, k) mod m` for an arbitrary modulus `m`, where `C(n, k) = 0` if `n < k`. A prime
+/// factor `p` of `m` is handled by Lucas' theorem. For a prime power `p^e` with `e >= 2`, the
+/// factorials are split into their `p`-free parts, computed with a table of the units modulo
+/// `p^e`, and the power of `p` given by Kummer's theorem. The results are combined by the Chinese
+/// remainder theorem.
+///
+/// # Complexity
+/// Time: O(m^{1/4} log m + sum of min(k, p) log n over prime factors p + sum of p^e over prime
+/// power factors with e >= 2)
+pub fn binom_mod(n: u64, k: u64, m: u64) -> u64 {
+    debug_assert!(m > 0, "m must not be zero");
+    if n < k || m == 1 {
+        return 0;
+    }
+    let mut res = 0;
+    let mut modulus = 1;
+    for (p, e) in factorize(m) {
+        let q = p.pow(e);
+        let r = if e == 1 {
+            binom_mod_prime(n, k, p)
+        } else {
+            binom_mod_prime_power(n, k, p, e, q)
+        };
+        let t = mul_mod(sub_mod(r, res % q, q), inv_mod(modulus % q, q), q);
+        res += modulus * t;
+        modulus *= q;
+    }
+    res
+}
+
+/// Returns `C(n, k) mod p` for a prime `p` by Lucas' theorem.
+fn binom_mod_prime(mut n: u64, mut k: u64, p: u64) -> u64 {
+    let mut res = 1 % p;
+    while k > 0 {
+        let (a, b) = (n % p, k % p);
+        if a < b {
+            return 0;
+        }
+        let b = b.min(a - b);
+        let (mut num, mut den) = (1 % p, 1 % p);
+        for i in 0..b {
+            num = mul_mod(num, a - i, p);
+            den = mul_mod(den, i + 1, p);
+        }
+        res = mul_mod(res, mul_mod(num, inv_mod(den, p), p), p);
+        n /= p;
+        k /= p;
+    }
+    res
+}
+
+/// Returns `C(n, k) mod q` for `q = p^e` with `e >= 2`.
+fn binom_mod_prime_power(n: u64, k: u64, p: u64, e: u32, q: u64) -> u64 {
+    let mut v = 0;
+    let (mut a, mut b, mut c) = (n / p, k / p, (n - k) / p);
+    while a > 0 {
+        v += a - b - c;
+        (a, b, c) = (a / p, b / p, c / p);
+    }
+    if v >= e as u64 {
+        return 0;
+    }
+    // `units[i]` is the product of all `1 <= j <= i` not divisible by `p`, modulo `q`.
+    let mut units = vec![1 % q; q as usize + 1];
+    for i in 1..=q as usize {
+        units[i] = if i as u64 % p == 0 {
+            units[i - 1]
+        } else {
+            mul_mod(units[i - 1], i as u64, q)
+        };
+    }
+    // The product of all units modulo `q` is `±1` by the generalized Wilson's theorem, so only
+    // the parity of its exponent matters.
+    let full = units[q as usize];
+    // Returns `x! / p^{v_p(x!)} mod q`.
+    let free = |mut x: u64| {
+        let mut res = 1 % q;
+        while x > 0 {
+            res = mul_mod(res, units[(x % q) as usize], q);
+            if (x / q) & 1 == 1 {
+                res = mul_mod(res, full, q);
+            }
+            x /= p;
+        }
+        res
+    };
+    let den = mul_mod(free(k), free(n - k), q);
+    mul_mod(mul_mod(free(n), inv_mod(den, q), q), p.pow(v as u32), q)
+}
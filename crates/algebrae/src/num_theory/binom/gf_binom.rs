@@ -39,6 +39,38 @@ impl<const P: u32> GfBinom<P> {
         }
     }
 
+    /// Grows the table in place so that it covers factorials up to `n` including `n`. If `n` is
+    /// not larger than the current limit, nothing happens.
+    ///
+    /// # Complexity
+    /// Time: O(n - len + log P)
+    pub fn extend(&mut self, n: usize) {
+        let old = self.len();
+        if n <= old {
+            return;
+        }
+        let mut fact = std::mem::take(&mut self.fact).into_vec();
+        let mut inv_fact = std::mem::take(&mut self.inv_fact).into_vec();
+        fact.reserve(n - old);
+        inv_fact.reserve(n - old);
+        unsafe {
+            let f = fact.as_mut_ptr();
+            for i in old + 1..=n {
+                f.add(i).write(*f.add(i - 1) * Gf::<P>::from(i));
+            }
+            fact.set_len(n + 1);
+
+            let inv_f = inv_fact.as_mut_ptr();
+            inv_f.add(n).write((*f.add(n)).inv());
+            for i in (old + 2..=n).rev() {
+                inv_f.add(i - 1).write(*inv_f.add(i) * Gf::<P>::from(i));
+            }
+            inv_fact.set_len(n + 1);
+        }
+        self.fact = fact.into_boxed_slice();
+        self.inv_fact = inv_fact.into_boxed_slice();
+    }
+
     /// Returns factorial of `n`.
     ///
     /// # Complexity
@@ -155,6 +187,37 @@ impl<const P: u32> GfBinom<P> {
         }
     }
 
+    /// Returns sum_{k=0}^{n} binom(n, k) = 2^n. This does not depend on the table size.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn sum_binom_row(&self, n: usize) -> Gf<P> {
+        Gf::new(2).pow(n as u64)
+    }
+
+    /// Returns sum_{k=0}^{n} (-1)^k binom(n, k), which is 1 if n = 0 and 0 otherwise.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn alternating_sum(&self, n: usize) -> Gf<P> {
+        if n == 0 { Gf::new(1) } else { Gf::new(0) }
+    }
+
+    /// Returns sum_{i=r}^{n} binom(i, r) = binom(n+1, r+1) by the hockey-stick identity, if n < r
+    /// returns 0.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn hockey_stick(&self, n: usize, r: usize) -> Gf<P> {
+        debug_assert!(
+            n < self.len(),
+            "n+1 is out of bounds: n={}, max={}",
+            n,
+            self.len()
+        );
+        self.binom(n + 1, r + 1)
+    }
+
     /// Returns the limit of number.
     ///
     /// # Complexity
@@ -1,2 +1,3 @@
+pub mod binom_mod;
 pub mod gf_binom;
 pub mod int_binom;
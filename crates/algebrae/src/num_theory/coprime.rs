@@ -0,0 +1,44 @@
+use crate::num_theory::factorize;
+
+/// Returns the number of integers in [1, limit] coprime to `n` by inclusion-exclusion over the
+/// distinct prime factors of `n`. `count_coprime(n, n)` equals Euler's totient of `n`.
+///
+/// # Complexity
+/// Time: O(n^{1/4} log n + 2^k), where k is the number of distinct prime factors of `n`.
+pub fn count_coprime(n: u64, limit: u64) -> u64 {
+    let primes: Vec<u64> = factorize(n).into_iter().map(|(p, _)| p).collect();
+    let k = primes.len();
+    let mut prod = vec![1u64; 1 << k];
+    let mut res = 0i128;
+    unsafe {
+        let prod = prod.as_mut_ptr();
+        let primes = primes.as_ptr();
+        for s in 0..1usize << k {
+            if s > 0 {
+                let low = s.trailing_zeros() as usize;
+                *prod.add(s) = *prod.add(s & (s - 1)) * *primes.add(low);
+            }
+            let cnt = (limit / *prod.add(s)) as i128;
+            if s.count_ones() & 1 == 0 {
+                res += cnt;
+            } else {
+                res -= cnt;
+            }
+        }
+    }
+    res as u64
+}
+
+/// Returns the number of integers in [lo, hi] coprime to `n`. When `lo > hi`, returns 0.
+///
+/// # Complexity
+/// Time: O(n^{1/4} log n + 2^k), where k is the number of distinct prime factors of `n`.
+pub fn coprime_count_in_range(n: u64, lo: u64, hi: u64) -> u64 {
+    if lo > hi {
+        return 0;
+    }
+    if lo == 0 {
+        return count_coprime(n, hi) + (n == 1) as u64;
+    }
+    count_coprime(n, hi) - count_coprime(n, lo - 1)
+}
@@ -0,0 +1,63 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Counts the integers `x` in `[0, n]` accepted by a digit automaton in base `base`. The digits
+/// of `x` are fed from the most significant one, starting from `init`, without leading zeros, so
+/// that `0` is judged by `accept(&init)` alone. States of equal value are merged, and the tight
+/// prefix of `n` is tracked separately.
+///
+/// # Complexity
+/// Time: O(L s base) hash operations, where L is the number of digits of n and s is the number
+/// of distinct reachable states.
+pub fn digit_dp<S, T, A>(n: u64, base: u64, init: S, trans: T, accept: A) -> u64
+where
+    S: Clone + Eq + Hash,
+    T: Fn(&S, u64) -> S,
+    A: Fn(&S) -> bool,
+{
+    debug_assert!(base >= 2, "base must be at least 2: base={}", base);
+    let mut digits = Vec::new();
+    let mut m = n;
+    while m > 0 {
+        digits.push(m % base);
+        m /= base;
+    }
+    // Numbers already below the prefix of `n`, which are either still all zeros (`zero`) or
+    // started and grouped by state (`free`), and the state of the prefix of `n` itself.
+    let mut free: HashMap<S, u64> = HashMap::new();
+    let mut zero = false;
+    let mut tight = init.clone();
+    for (i, &d) in digits.iter().rev().enumerate() {
+        let mut next: HashMap<S, u64> = HashMap::new();
+        for (s, &c) in free.iter() {
+            for x in 0..base {
+                *next.entry(trans(s, x)).or_insert(0) += c;
+            }
+        }
+        if zero {
+            for x in 1..base {
+                *next.entry(trans(&init, x)).or_insert(0) += 1;
+            }
+        }
+        for x in 0..d {
+            if i == 0 && x == 0 {
+                zero = true;
+            } else {
+                *next.entry(trans(&tight, x)).or_insert(0) += 1;
+            }
+        }
+        tight = trans(&tight, d);
+        free = next;
+    }
+    let mut res = free
+        .iter()
+        .filter(|(s, _)| accept(s))
+        .map(|(_, &c)| c)
+        .sum::<u64>();
+    if zero && accept(&init) {
+        res += 1;
+    }
+    if accept(&tight) {
+        res += 1;
+    }
+    res
+}
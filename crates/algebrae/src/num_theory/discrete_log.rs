@@ -0,0 +1,54 @@
+use crate::num_theory::gcd;
+
+/// Returns the minimum `x >= 0` such that `a^x = b (mod m)`, or `None` if no such `x` exists.
+/// `m` may be composite: the factors shared by `a` and `m` are peeled off first, then
+/// baby-step giant-step runs on the coprime part. `0^0` is treated as 1.
+///
+/// # Complexity
+/// Time: O(sqrt(m) + log^2 m)
+pub fn discrete_log_mod(a: u64, b: u64, m: u64) -> Option<u64> {
+    debug_assert!(m > 0, "m must not be zero");
+    let mul = |x: u64, y: u64, m: u64| (x as u128 * y as u128 % m as u128) as u64;
+    let (mut a, mut b, mut m) = (a % m, b % m, m);
+    // Solves `k * a^x = b (mod m)` for the remaining exponent.
+    let mut k = 1 % m;
+    let mut add = 0;
+    loop {
+        if k == b {
+            return Some(add);
+        }
+        let g = gcd(a, m);
+        if g == 1 {
+            break;
+        }
+        if b % g != 0 {
+            return None;
+        }
+        b /= g;
+        m /= g;
+        add += 1;
+        k = mul(k % m, (a / g) % m, m);
+        a %= m;
+        b %= m;
+    }
+
+    let n = m.isqrt() + 1;
+    let mut baby = std::collections::HashMap::with_capacity(n as usize);
+    let mut cur = b;
+    for j in 0..n {
+        baby.insert(cur, j);
+        cur = mul(cur, a, m);
+    }
+    let mut giant = 1 % m;
+    for _ in 0..n {
+        giant = mul(giant, a, m);
+    }
+    let mut cur = k;
+    for i in 1..=n {
+        cur = mul(cur, giant, m);
+        if let Some(&j) = baby.get(&cur) {
+            return Some(add + i * n - j);
+        }
+    }
+    None
+}
@@ -0,0 +1,120 @@
+use crate::{algebra::Rig, num_theory::Gf};
+
+/// A pair of residues modulo two primes `P1` and `P2` with elementwise arithmetic, standing for
+/// a residue modulo `P1 P2`. Useful as a double hash, and for recovering values below `P1 P2`
+/// by Garner's algorithm.
+///
+/// # Complexity
+/// Space: O(1)
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DualGf<const P1: u32, const P2: u32>(pub Gf<P1>, pub Gf<P2>);
+
+impl<const P1: u32, const P2: u32> DualGf<P1, P2> {
+    /// Creates a new element from a value, reduced modulo both primes.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new(value: u64) -> Self {
+        Self(Gf::from(value), Gf::from(value))
+    }
+
+    /// Returns `self^exp` computed by binary exponentiation.
+    ///
+    /// # Complexity
+    /// Time: O(log exp)
+    pub fn pow(&self, exp: u64) -> Self {
+        Self(self.0.pow(exp), self.1.pow(exp))
+    }
+
+    /// Returns the multiplicative inverse, which must exist modulo both primes.
+    ///
+    /// # Complexity
+    /// Time: O(log P1 + log P2)
+    pub fn inv(&self) -> Self {
+        Self(self.0.inv(), self.1.inv())
+    }
+
+    /// Returns the unique `x` in `[0, P1 P2)` with the two residues by Garner's algorithm,
+    /// reduced modulo `m`. The primes must be distinct.
+    ///
+    /// # Complexity
+    /// Time: O(log P2)
+    pub fn reconstruct(&self, m: u64) -> u64 {
+        debug_assert!(m > 0, "m must not be zero");
+        debug_assert!(P1 != P2, "moduli must be distinct: P1={}, P2={}", P1, P2);
+        let r1 = self.0.0 as u64;
+        let t = (self.1 - Gf::<P2>::from(r1)) * Gf::<P2>::from(P1).inv();
+        (r1 + P1 as u64 * t.0 as u64) % m
+    }
+}
+
+macro_rules! impl_dual_gf_from {
+    ($($src:ty), *) => {
+        $(
+            impl<const P1: u32, const P2: u32> From<$src> for DualGf<P1, P2> {
+                fn from(x: $src) -> Self {
+                    Self(Gf::from(x), Gf::from(x))
+                }
+            }
+        )*
+    };
+}
+
+impl_dual_gf_from!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl<const P1: u32, const P2: u32> std::fmt::Debug for DualGf<P1, P2> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl<const P1: u32, const P2: u32> std::ops::Neg for DualGf<P1, P2> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(-self.0, -self.1)
+    }
+}
+
+macro_rules! impl_dual_gf_binop {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident);* $(;)?) => {
+        $(
+            impl<const P1: u32, const P2: u32> std::ops::$trait for DualGf<P1, P2> {
+                type Output = Self;
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self(
+                        std::ops::$trait::$method(self.0, rhs.0),
+                        std::ops::$trait::$method(self.1, rhs.1),
+                    )
+                }
+            }
+
+            impl<const P1: u32, const P2: u32> std::ops::$assign_trait for DualGf<P1, P2> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Self) {
+                    std::ops::$assign_trait::$assign_method(&mut self.0, rhs.0);
+                    std::ops::$assign_trait::$assign_method(&mut self.1, rhs.1);
+                }
+            }
+        )*
+    };
+}
+
+impl_dual_gf_binop! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+}
+
+impl<const P1: u32, const P2: u32> Rig for DualGf<P1, P2> {
+    fn zero() -> Self {
+        Self(Gf::zero(), Gf::zero())
+    }
+    fn one() -> Self {
+        Self(Gf::one(), Gf::one())
+    }
+}
@@ -0,0 +1,42 @@
+/// Returns the sum of floor((a * i + b) / m) over 0 <= i < n. The result must fit in u64.
+///
+/// # Complexity
+/// Time: O(log(m + a))
+pub fn floor_sum(n: u64, m: u64, a: u64, b: u64) -> u64 {
+    debug_assert!(m > 0, "m must be positive");
+    let (mut n, mut m, mut a, mut b) = (n as u128, m as u128, a as u128, b as u128);
+    let mut res = 0u128;
+    loop {
+        if a >= m {
+            res += n * n.saturating_sub(1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            res += n * (b / m);
+            b %= m;
+        }
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+    res as u64
+}
+
+/// Returns the number of lattice points (x, y) with 1 <= x <= n and 1 <= y lying on or below the
+/// line c * y = a * x + b, that is the sum of floor((a * x + b) / c) over 1 <= x <= n. The result
+/// must fit in u64.
+///
+/// # Complexity
+/// Time: O(log(c + a))
+pub fn lattice_under_line(n: u64, a: u64, b: u64, c: u64) -> u64 {
+    debug_assert!(c > 0, "c must be positive");
+    // Shifting x = i + 1 turns the sum into floor_sum over 0 <= i < n, reducing a + b first so
+    // that it stays within u64.
+    let q = (a as u128 + b as u128) / c as u128;
+    let r = ((a as u128 + b as u128) % c as u128) as u64;
+    (floor_sum(n, c, a, r) as u128 + q * n as u128) as u64
+}
@@ -3,7 +3,7 @@
 /// # Complexity
 /// Space: O(1)
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Gf<const P: u32>(u32);
+pub struct Gf<const P: u32>(pub(crate) u32);
 
 impl<const P: u32> Gf<P> {
     /// Creates a new element from a value, reduced modulo `P`.
@@ -47,7 +47,7 @@ macro_rules! impl_gf_new_from_signed {
             impl<const P: u32> From<$src> for Gf<P> {
                 fn from(x: $src) -> Self {
                     if x < 0 {
-                        - Self::new((x as i128).rem_euclid(P as i128) as u32)
+                        Self::new((x as i128).rem_euclid(P as i128) as u32)
                     } else {
                         Self::new(x as u32)
                     }
@@ -0,0 +1,88 @@
+use crate::num_theory::primitive_root;
+
+/// Index (discrete logarithm) and antilog tables modulo a prime `p` with respect to a primitive
+/// root `g`, so that `log` and `antilog` are single lookups instead of baby-step giant-step runs.
+///
+/// # Complexity
+/// Space: O(p)
+pub struct IndexTable {
+    p: u32,
+    root: u32,
+    // `log[x]` is the index of `x` for `1 <= x < p`, and `antilog[k] = g^k` for `0 <= k < p - 1`.
+    log: Box<[u32]>,
+    antilog: Box<[u32]>,
+}
+
+impl IndexTable {
+    /// Creates the tables for a prime `p`.
+    ///
+    /// # Complexity
+    /// Time: O(p)
+    pub fn new(p: u32) -> Self {
+        debug_assert!(p >= 2, "p must be a prime: p={}", p);
+        let root = primitive_root(p as u64) as u32;
+        let mut log = vec![0; p as usize];
+        let mut antilog = vec![0; p as usize - 1];
+        let mut x = 1u32;
+        for (k, a) in antilog.iter_mut().enumerate() {
+            *a = x;
+            log[x as usize] = k as u32;
+            x = (x as u64 * root as u64 % p as u64) as u32;
+        }
+        Self {
+            p,
+            root,
+            log: log.into_boxed_slice(),
+            antilog: antilog.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the primitive root `g` the tables are built on.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn root(&self) -> u32 {
+        self.root
+    }
+
+    /// Returns the minimum `k >= 0` such that `g^k = x (mod p)`. `x` must not be divisible by
+    /// `p`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn log(&self, x: u64) -> u64 {
+        let x = x % self.p as u64;
+        debug_assert!(x != 0, "zero has no index modulo {}", self.p);
+        self.log[x as usize] as u64
+    }
+
+    /// Returns `g^k mod p`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn antilog(&self, k: u64) -> u64 {
+        self.antilog[(k % (self.p as u64 - 1)) as usize] as u64
+    }
+
+    /// Returns `x^k mod p`, where `0^0` is treated as 1.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn pow(&self, x: u64, k: u64) -> u64 {
+        if x % self.p as u64 == 0 {
+            return (k == 0) as u64;
+        }
+        let e = self.log(x) as u128 * k as u128 % (self.p as u128 - 1);
+        self.antilog[e as usize] as u64
+    }
+
+    /// Returns the prime modulus `p`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn modulus(&self) -> u32 {
+        self.p
+    }
+}
@@ -1,13 +1,35 @@
 mod binom;
+mod coprime;
+mod digit_dp;
+mod discrete_log;
+mod dual_gf;
+mod floor_sum;
 mod gcd;
 mod gf;
+mod index_table;
+mod multiplicative_sum;
 mod pow_mod;
 mod prime;
+mod rational;
+mod rational_reconstruct;
 mod sieve;
+mod sqrt_mod;
 
-pub use binom::{gf_binom::GfBinom, int_binom::IntBinom};
+pub use binom::{binom_mod::binom_mod, gf_binom::GfBinom, int_binom::IntBinom};
+pub use coprime::{coprime_count_in_range, count_coprime};
+pub use digit_dp::digit_dp;
+pub use discrete_log::discrete_log_mod;
+pub use dual_gf::DualGf;
+pub use floor_sum::{floor_sum, lattice_under_line};
 pub use gcd::{ext_gcd, gcd, lcm};
 pub use gf::Gf;
+pub use index_table::IndexTable;
+pub use multiplicative_sum::{dirichlet_prefix_sum, sum_mobius, sum_phi};
 pub use pow_mod::pow_mod;
-pub use prime::{factorize, is_prime, primitive_root};
-pub use sieve::eratosthenes::SieveEratosthenes;
+pub use prime::{
+    factorize, is_prime, is_prime_with_witnesses, multiplicative_order, primitive_root,
+};
+pub use rational::Rational;
+pub use rational_reconstruct::rational_reconstruct;
+pub use sieve::{eratosthenes::SieveEratosthenes, linear::SieveLinear};
+pub use sqrt_mod::sqrt_mod;
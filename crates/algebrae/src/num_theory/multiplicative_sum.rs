@@ -0,0 +1,119 @@
+use crate::num_theory::SieveLinear;
+
+/// Computes `F(n) = sum_{i=1}^{n} f(i)` for an arithmetic function `f` by the Dirichlet
+/// hyperbola method, given `g` with `g(1) = 1` and `h = f * g` (Dirichlet convolution) whose
+/// prefix sums `g_prefix` and `h_prefix` are cheap. `small[i]` must hold `F(i)` for every
+/// `i < small.len()`, with `small[0] = 0`, and may use a narrower type than the result to save
+/// memory. All values `F(n / k)` are memoized.
+///
+/// # Complexity
+/// Time: O(n / sqrt(L) + sqrt(n) log n) with L = small.len(), O(n^{2/3}) for L = n^{2/3}
+pub fn dirichlet_prefix_sum<S, T, G, H>(n: u64, small: &[S], g_prefix: G, h_prefix: H) -> T
+where
+    S: Copy + Into<T>,
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+    G: Fn(u64) -> T,
+    H: Fn(u64) -> T,
+{
+    debug_assert!(small.len() >= 2, "small must contain F(0) and F(1)");
+    if n < small.len() as u64 {
+        return small[n as usize].into();
+    }
+    let sq = n.isqrt();
+    // `lo[v]` holds F(v) for v <= sqrt(n), and `hi[k]` holds F(n / k) for n / k > sqrt(n).
+    let mut lo: Vec<T> = Vec::with_capacity(sq as usize + 1);
+    let mut hi: Vec<T> = vec![small[0].into(); sq as usize + 1];
+    let calc = |v: u64, lo: &[T], hi: &[T]| -> T {
+        let get = |q: u64| {
+            if q < small.len() as u64 {
+                small[q as usize].into()
+            } else if q <= sq {
+                lo[q as usize]
+            } else {
+                hi[(n / q) as usize]
+            }
+        };
+        let mut res = h_prefix(v);
+        let mut l = 2;
+        while l <= v {
+            let q = v / l;
+            let r = v / q;
+            res = res - (g_prefix(r) - g_prefix(l - 1)) * get(q);
+            l = r + 1;
+        }
+        res
+    };
+    for v in 0..=sq {
+        let x = if v < small.len() as u64 {
+            small[v as usize].into()
+        } else {
+            calc(v, &lo, &hi)
+        };
+        lo.push(x);
+    }
+    for k in (1..=sq).rev() {
+        let v = n / k;
+        if v > sq {
+            hi[k as usize] = if v < small.len() as u64 {
+                small[v as usize].into()
+            } else {
+                calc(v, &lo, &hi)
+            };
+        }
+    }
+    hi[1]
+}
+
+/// Returns `sum_{i=1}^{n} phi(i)`, where `phi` is Euler's totient function. The totients up to
+/// about `n^{2/3}` are sieved into a single `u64` buffer and prefix-summed in place, and only the
+/// O(sqrt(n)) memoized large values use `u128`.
+///
+/// # Complexity
+/// Time: O(n^{2/3})
+pub fn sum_phi(n: u64) -> u128 {
+    let l = small_limit(n);
+    let mut small: Vec<u64> = (0..=l as u64).collect();
+    for p in 2..=l {
+        // `small[p] == p` still holds exactly when no smaller prime divides `p`.
+        if small[p] == p as u64 {
+            for j in (p..=l).step_by(p) {
+                small[j] -= small[j] / p as u64;
+            }
+        }
+    }
+    for i in 2..=l {
+        small[i] += small[i - 1];
+    }
+    dirichlet_prefix_sum(
+        n,
+        &small,
+        |x| x as u128,
+        |x| x as u128 * (x as u128 + 1) / 2,
+    )
+}
+
+/// Returns `sum_{i=1}^{n} mu(i)` (the Mertens function), where `mu` is the Möbius function.
+///
+/// # Complexity
+/// Time: O(n^{2/3})
+pub fn sum_mobius(n: u64) -> i64 {
+    let sieve = SieveLinear::new(small_limit(n));
+    let l = sieve.len();
+    let mut mu = vec![0i64; l + 1];
+    let mut small = vec![0i64; l + 1];
+    mu[1] = 1;
+    small[1] = 1;
+    for i in 2..=l {
+        let p = sieve.smallest_prime_factor(i);
+        let m = i / p;
+        mu[i] = if m % p == 0 { 0 } else { -mu[m] };
+        small[i] = small[i - 1] + mu[i];
+    }
+    dirichlet_prefix_sum(n, &small, |x| x as i64, |x| (x >= 1) as i64)
+}
+
+/// Returns the sieve limit about `n^{2/3}`, at least 1 and at most `n`.
+fn small_limit(n: u64) -> usize {
+    let l = (n as f64).powf(2.0 / 3.0) as u64;
+    l.clamp(1, n.max(1)) as usize
+}
@@ -19,3 +19,23 @@ pub fn pow_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
     }
     res as u64
 }
+
+pub(crate) fn sub_mod(a: u64, b: u64, m: u64) -> u64 {
+    if a >= b { a - b } else { a + (m - b) }
+}
+
+pub(crate) fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// Returns the inverse of `a` modulo `m` for coprime `a` and `m`.
+pub(crate) fn inv_mod(a: u64, m: u64) -> u64 {
+    let (mut r0, mut r1) = (m as i128, a as i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    t0.rem_euclid(m as i128) as u64
+}
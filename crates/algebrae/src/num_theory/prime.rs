@@ -5,6 +5,24 @@ use crate::num_theory::{gcd, pow_mod};
 /// # Complexity
 /// Time: O(log^2 n), Space: O(1)
 pub fn is_prime(n: u64) -> bool {
+    const SMALL: [u64; 3] = [2, 7, 61];
+    const LARGE: [u64; 7] = [2, 325, 9_375, 28_178, 450_775, 9_780_504, 1_795_265_022];
+
+    if n < 4_759_123_141 {
+        is_prime_with_witnesses(n, &SMALL)
+    } else {
+        is_prime_with_witnesses(n, &LARGE)
+    }
+}
+
+/// Returns whether `n` passes the Miller-Rabin test for every base in `witnesses`. A `true`
+/// answer proves primality only when the witness set is known to be deterministic for the range
+/// of `n`, and otherwise `n` may be a strong pseudoprime to all the bases. Bases are reduced
+/// modulo `n`, and bases divisible by `n` are skipped.
+///
+/// # Complexity
+/// Time: O(k log n), where k = witnesses.len().
+pub fn is_prime_with_witnesses(n: u64, witnesses: &[u64]) -> bool {
     if n == 0 || n == 1 {
         return false;
     } else if n == 2 {
@@ -14,22 +32,10 @@ pub fn is_prime(n: u64) -> bool {
     }
     let r = (n - 1).trailing_zeros();
     let d = (n - 1) >> r;
-
-    const SMALL: [u64; 3] = [2, 7, 61];
-    const LARGE: [u64; 7] = [2, 325, 9_375, 28_178, 450_775, 9_780_504, 1_795_265_022];
-
-    if n < 4_759_123_141 {
-        miller_rabin(n, d, r, &SMALL)
-    } else {
-        miller_rabin(n, d, r, &LARGE)
-    }
-}
-
-#[inline(always)]
-fn miller_rabin<const N: usize>(n: u64, d: u64, r: u32, witnesses: &[u64; N]) -> bool {
     for &x in witnesses {
-        if x >= n {
-            break;
+        let x = x % n;
+        if x == 0 {
+            continue;
         }
         let mut pow = pow_mod(x, d, n);
         if pow == 1 || pow == n - 1 {
@@ -145,3 +151,26 @@ pub fn primitive_root(p: u64) -> u64 {
     }
     unreachable!();
 }
+
+/// Returns the multiplicative order of `a` modulo `m`, the smallest positive `k` with
+/// `a^k = 1 (mod m)`, or `None` if `a` and `m` are not coprime. The order divides Euler's totient
+/// of `m`, so prime factors are stripped from it while the power stays 1.
+///
+/// # Complexity
+/// Time: O(m^{1/4} log m)
+pub fn multiplicative_order(a: u64, m: u64) -> Option<u64> {
+    debug_assert!(m > 0, "modulus must not be zero");
+    if gcd(a % m, m) != 1 {
+        return None;
+    }
+    let phi = factorize(m)
+        .into_iter()
+        .fold(1, |acc, (p, e)| acc * p.pow(e - 1) * (p - 1));
+    let mut res = phi;
+    for (q, _) in factorize(phi) {
+        while res % q == 0 && pow_mod(a, res / q, m) == 1 {
+            res /= q;
+        }
+    }
+    Some(res)
+}
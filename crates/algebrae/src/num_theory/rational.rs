@@ -0,0 +1,210 @@
+use crate::algebra::Rig;
+
+/// A rational number kept in lowest terms with a positive denominator.
+///
+/// # Complexity
+/// Space: O(1)
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    /// Creates a new rational number `num / den` in lowest terms.
+    ///
+    /// # Complexity
+    /// Time: O(log(|num| + |den|))
+    pub fn new(num: i128, den: i128) -> Self {
+        debug_assert!(den != 0, "denominator must not be zero");
+        let g = gcd(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Self {
+                num: -num,
+                den: -den,
+            }
+        } else {
+            Self { num, den }
+        }
+    }
+
+    /// Returns the numerator.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+
+    /// Returns the denominator, which is always positive.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    /// Returns the multiplicative inverse `1 / self`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn inv(&self) -> Self {
+        debug_assert!(self.num != 0, "zero has no inverse");
+        if self.num < 0 {
+            Self {
+                num: -self.den,
+                den: -self.num,
+            }
+        } else {
+            Self {
+                num: self.den,
+                den: self.num,
+            }
+        }
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+macro_rules! impl_rational_from_int {
+    ($($src:ty), *) => {
+        $(
+            impl From<$src> for Rational {
+                fn from(x: $src) -> Self {
+                    Self {
+                        num: x as i128,
+                        den: 1,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_rational_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl std::fmt::Debug for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+impl std::fmt::Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+impl std::ops::Neg for Rational {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl std::ops::Add for Rational {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let g = gcd(self.den as u128, rhs.den as u128) as i128;
+        Self::new(
+            self.num * (rhs.den / g) + rhs.num * (self.den / g),
+            self.den / g * rhs.den,
+        )
+    }
+}
+
+impl std::ops::Sub for Rational {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl std::ops::Mul for Rational {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let g1 = gcd(self.num.unsigned_abs(), rhs.den as u128) as i128;
+        let g2 = gcd(rhs.num.unsigned_abs(), self.den as u128) as i128;
+        Self {
+            num: (self.num / g1) * (rhs.num / g2),
+            den: (self.den / g2) * (rhs.den / g1),
+        }
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl std::ops::Div for Rational {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl std::ops::AddAssign for Rational {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::SubAssign for Rational {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::MulAssign for Rational {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl std::ops::DivAssign for Rational {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Rig for Rational {
+    fn zero() -> Self {
+        Self { num: 0, den: 1 }
+    }
+    fn one() -> Self {
+        Self { num: 1, den: 1 }
+    }
+}
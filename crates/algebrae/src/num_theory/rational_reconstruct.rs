@@ -0,0 +1,25 @@
+use crate::num_theory::gcd;
+
+/// Returns the unique `(num, den)` with `den > 0`, `gcd(num, den) = 1`, `|num| <= N`,
+/// `den <= max(N, 1)` and `num = r * den (mod m)`, where `N = floor(sqrt((m - 1) / 2))`. The bound
+/// guarantees uniqueness, and the candidate is found by running the extended Euclidean algorithm
+/// on `(m, r)` until the remainder drops to at most `N`. Returns `None` if no such fraction exists.
+///
+/// # Complexity
+/// Time: O(log m)
+pub fn rational_reconstruct(r: u64, m: u64) -> Option<(i64, i64)> {
+    debug_assert!(m > 0, "m must not be zero");
+    let bound = (((m - 1) >> 1) as u128).isqrt() as i128;
+    let (mut r0, mut r1) = (m as i128, (r % m) as i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 > bound {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    if t1.abs() > bound.max(1) || gcd(r1 as u64, t1.unsigned_abs() as u64) != 1 {
+        return None;
+    }
+    let (num, den) = if t1 < 0 { (-r1, -t1) } else { (r1, t1) };
+    Some((num as i64, den as i64))
+}
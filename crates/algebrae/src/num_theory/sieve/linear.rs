@@ -0,0 +1,163 @@
+/// A linear sieve structure holding the smallest prime factor of each number.
+///
+/// # Complexity
+/// Space: O(n)
+pub struct SieveLinear {
+    spf: Box<[u32]>,
+    primes: Box<[u32]>,
+}
+
+impl SieveLinear {
+    /// Creates a new linear sieve table up to `n` including `n`.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn new(n: usize) -> Self {
+        debug_assert!(n > 0, "n must not be zero");
+        debug_assert!(n <= u32::MAX as usize, "n is too large: n={}", n);
+        let mut spf = vec![0u32; n + 1];
+        let mut primes = Vec::new();
+        unsafe {
+            let s = spf.as_mut_ptr();
+            *s.add(1) = 1;
+            for i in 2..=n {
+                if *s.add(i) == 0 {
+                    *s.add(i) = i as u32;
+                    primes.push(i as u32);
+                }
+                let si = *s.add(i);
+                for &p in primes.iter() {
+                    if p > si || i * p as usize > n {
+                        break;
+                    }
+                    *s.add(i * p as usize) = p;
+                }
+            }
+        }
+        Self {
+            spf: spf.into_boxed_slice(),
+            primes: primes.into_boxed_slice(),
+        }
+    }
+
+    /// Returns whether `n` is prime.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn is_prime(&self, n: usize) -> bool {
+        debug_assert!(
+            n <= self.len(),
+            "n is out of bounds: n={}, max={}",
+            n,
+            self.len(),
+        );
+        n >= 2 && unsafe { *self.spf.get_unchecked(n) } as usize == n
+    }
+
+    /// Returns the smallest prime factor of `n`, where `n` is at least 2.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn smallest_prime_factor(&self, n: usize) -> usize {
+        debug_assert!(
+            (2..=self.len()).contains(&n),
+            "n is out of bounds: n={}, max={}",
+            n,
+            self.len(),
+        );
+        unsafe { *self.spf.get_unchecked(n) as usize }
+    }
+
+    /// Returns the largest prime factor of `n`, where `n` is at least 2.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn largest_prime_factor(&self, mut n: usize) -> usize {
+        debug_assert!(
+            (2..=self.len()).contains(&n),
+            "n is out of bounds: n={}, max={}",
+            n,
+            self.len(),
+        );
+        unsafe {
+            loop {
+                let p = *self.spf.get_unchecked(n) as usize;
+                if p == n {
+                    return p;
+                }
+                n /= p;
+            }
+        }
+    }
+
+    /// Returns the radical of `n`, the product of its distinct prime factors, where `n` is at
+    /// least 1 and `radical(1) = 1`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn radical(&self, mut n: usize) -> usize {
+        debug_assert!(
+            (1..=self.len()).contains(&n),
+            "n is out of bounds: n={}, max={}",
+            n,
+            self.len(),
+        );
+        let mut res = 1;
+        let mut last = 1;
+        unsafe {
+            while n > 1 {
+                let p = *self.spf.get_unchecked(n) as usize;
+                if p != last {
+                    res *= p;
+                    last = p;
+                }
+                n /= p;
+            }
+        }
+        res
+    }
+
+    /// Returns the prime factorization of `n` as `(prime, exponent)` pairs in ascending order,
+    /// where `n` is at least 1.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn factorize(&self, mut n: usize) -> Vec<(usize, u32)> {
+        debug_assert!(
+            (1..=self.len()).contains(&n),
+            "n is out of bounds: n={}, max={}",
+            n,
+            self.len(),
+        );
+        let mut res: Vec<(usize, u32)> = Vec::new();
+        unsafe {
+            while n > 1 {
+                let p = *self.spf.get_unchecked(n) as usize;
+                match res.last_mut() {
+                    Some((q, e)) if *q == p => *e += 1,
+                    _ => res.push((p, 1)),
+                }
+                n /= p;
+            }
+        }
+        res
+    }
+
+    /// Returns all primes up to `n` including `n`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    /// Returns the limit of number.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.spf.len() - 1
+    }
+}
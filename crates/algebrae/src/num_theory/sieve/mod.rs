@@ -1 +1,2 @@
 pub mod eratosthenes;
+pub mod linear;
@@ -0,0 +1,142 @@
+use crate::num_theory::{
+    factorize, pow_mod,
+    pow_mod::{inv_mod, mul_mod, sub_mod},
+};
+
+/// Returns all `x` in `0..m` with `x^2 = a (mod m)` in increasing order. `m` is factorized, the
+/// roots modulo each prime power are found by Tonelli-Shanks and Hensel lifting, and they are
+/// combined by the Chinese remainder theorem. The number of roots can be as large as about
+/// `sqrt(m)` (e.g. `a = 0` modulo a square), and all of them are returned.
+///
+/// # Complexity
+/// Time: O(m^{1/4} log m + k log m), where k is the number of roots.
+pub fn sqrt_mod(a: u64, m: u64) -> Vec<u64> {
+    debug_assert!(m > 0, "m must not be zero");
+    let mut res = vec![0];
+    let mut modulus = 1u64;
+    for (p, e) in factorize(m) {
+        let q = p.pow(e);
+        let roots = sqrt_mod_prime_power(a % q, p, e, q);
+        if roots.is_empty() {
+            return Vec::new();
+        }
+        // Combines `x = r (mod modulus)` and `x = s (mod q)`.
+        let inv = inv_mod(modulus % q, q);
+        let mut next = Vec::with_capacity(res.len() * roots.len());
+        for &r in res.iter() {
+            for &s in roots.iter() {
+                let t = mul_mod(sub_mod(s, r % q, q), inv, q);
+                next.push(r + modulus * t);
+            }
+        }
+        res = next;
+        modulus *= q;
+    }
+    res.sort_unstable();
+    res
+}
+
+/// Returns all square roots of `a` modulo `q = p^e`.
+fn sqrt_mod_prime_power(a: u64, p: u64, e: u32, q: u64) -> Vec<u64> {
+    if a == 0 {
+        // `x` must be divisible by `p^ceil(e / 2)`.
+        let step = p.pow(e.div_ceil(2));
+        return (0..q / step).map(|t| t * step).collect();
+    }
+    let mut k = 0;
+    let mut b = a;
+    while b % p == 0 {
+        b /= p;
+        k += 1;
+    }
+    if k & 1 == 1 {
+        return Vec::new();
+    }
+    // With `x = p^(k / 2) y`, the unit `y` solves `y^2 = b (mod p^(e - k))` and is determined
+    // modulo `p^(e - k / 2)`.
+    let f = e - k;
+    let qf = p.pow(f);
+    let b = b % qf;
+    let units = if p == 2 {
+        sqrt_mod_unit_two(b, f)
+    } else {
+        sqrt_mod_unit_odd(b, p, qf)
+    };
+    let (shift, lift) = (p.pow(k >> 1), p.pow(k >> 1));
+    let mut res = Vec::with_capacity(units.len() * lift as usize);
+    for &r in units.iter() {
+        for t in 0..lift {
+            res.push(mul_mod(shift, r + t * qf, q));
+        }
+    }
+    res
+}
+
+/// Returns the square roots of a unit `b` modulo `q = p^f` for an odd prime `p`.
+fn sqrt_mod_unit_odd(b: u64, p: u64, q: u64) -> Vec<u64> {
+    let Some(mut r) = tonelli_shanks(b % p, p) else {
+        return Vec::new();
+    };
+    // Newton's iteration doubles the p-adic precision of the root each step.
+    while mul_mod(r, r, q) != b {
+        let d = sub_mod(mul_mod(r, r, q), b, q);
+        r = sub_mod(r, mul_mod(d, inv_mod(mul_mod(2, r, q), q), q), q);
+    }
+    if r == q - r { vec![r] } else { vec![r, q - r] }
+}
+
+/// Returns the square roots of an odd `b` modulo `2^f`.
+fn sqrt_mod_unit_two(b: u64, f: u32) -> Vec<u64> {
+    match f {
+        0 => return vec![0],
+        1 => return vec![1],
+        2 => return if b & 3 == 1 { vec![1, 3] } else { Vec::new() },
+        _ => {}
+    }
+    if b & 7 != 1 {
+        return Vec::new();
+    }
+    let q = 1u64 << f;
+    let mut r = 1u64;
+    for i in 3..f {
+        if mul_mod(r, r, 1 << (i + 1)) != b & ((1 << (i + 1)) - 1) {
+            r += 1 << (i - 1);
+        }
+    }
+    let h = q >> 1;
+    vec![r, q - r, (r + h) % q, (q - r + h) % q]
+}
+
+/// Returns a square root of `a` modulo an odd prime `p`, or `None` if `a` is a non-residue.
+fn tonelli_shanks(a: u64, p: u64) -> Option<u64> {
+    if a == 0 {
+        return Some(0);
+    }
+    if pow_mod(a, (p - 1) >> 1, p) != 1 {
+        return None;
+    }
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let mut z = 2;
+    while pow_mod(z, (p - 1) >> 1, p) != p - 1 {
+        z += 1;
+    }
+    let mut c = pow_mod(z, q, p);
+    let mut r = pow_mod(a, (q + 1) >> 1, p);
+    let mut t = pow_mod(a, q, p);
+    let mut m = s;
+    while t != 1 {
+        let mut i = 0;
+        let mut u = t;
+        while u != 1 {
+            u = mul_mod(u, u, p);
+            i += 1;
+        }
+        let b = pow_mod(c, 1 << (m - i - 1), p);
+        r = mul_mod(r, b, p);
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        m = i;
+    }
+    Some(r)
+}
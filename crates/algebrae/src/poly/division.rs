@@ -0,0 +1,83 @@
+use crate::{conv::ntt::multiply, num_theory::Gf};
+
+/// Computes the first `n` coefficients of `1 / f` by Newton's iteration. `f[0]` must be nonzero.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn inv_series<const P: u32>(f: &[Gf<P>], n: usize) -> Vec<Gf<P>> {
+    debug_assert!(
+        f.first().is_some_and(|&c| c != Gf::new(0)),
+        "constant term must not be zero"
+    );
+    let mut res = vec![f[0].inv()];
+    let mut m = 1;
+    while m < n {
+        m <<= 1;
+        let mut g = multiply(f[..m.min(f.len())].to_vec(), res.clone());
+        g.resize(m, Gf::new(0));
+        for c in g.iter_mut() {
+            *c = -*c;
+        }
+        g[0] += Gf::new(2);
+        res = multiply(res, g);
+        res.truncate(m);
+    }
+    res.truncate(n);
+    res
+}
+
+/// Returns `(q, r)` with `a = q b + r` and `deg r < deg b`, both without trailing zeros. `b` must
+/// have a nonzero leading coefficient.
+///
+/// # Complexity
+/// Time: O(n log n), where n = a.len().
+pub fn div_rem<const P: u32>(a: &[Gf<P>], b: &[Gf<P>]) -> (Vec<Gf<P>>, Vec<Gf<P>>) {
+    debug_assert!(
+        b.last().is_some_and(|&c| c != Gf::new(0)),
+        "leading coefficient of divisor must not be zero"
+    );
+    let mut a = a.to_vec();
+    trim(&mut a);
+    if a.len() < b.len() {
+        return (Vec::new(), a);
+    }
+    let k = a.len() - b.len() + 1;
+    let (mut q, mut r) = if b.len() <= 64 || k <= 64 {
+        // Schoolbook division is faster for a short divisor or quotient, and leaves the remainder
+        // in the low coefficients of the working buffer.
+        let inv = b[b.len() - 1].inv();
+        let mut q = vec![Gf::new(0); k];
+        for i in (0..k).rev() {
+            let c = a[i + b.len() - 1] * inv;
+            q[i] = c;
+            for (j, &y) in b.iter().enumerate() {
+                a[i + j] -= c * y;
+            }
+        }
+        a.truncate(b.len() - 1);
+        (q, a)
+    } else {
+        let ra: Vec<Gf<P>> = a.iter().rev().take(k).copied().collect();
+        let rb: Vec<Gf<P>> = b.iter().rev().copied().collect();
+        let mut q = multiply(ra, inv_series(&rb, k));
+        q.truncate(k);
+        q.reverse();
+        let qb = multiply(q.clone(), b.to_vec());
+        let r: Vec<Gf<P>> = a[..b.len() - 1]
+            .iter()
+            .zip(qb)
+            .map(|(&x, y)| x - y)
+            .collect();
+        (q, r)
+    };
+    trim(&mut q);
+    trim(&mut r);
+    (q, r)
+}
+
+/// Removes trailing zero coefficients.
+pub(crate) fn trim<const P: u32>(a: &mut Vec<Gf<P>>) {
+    while a.last() == Some(&Gf::new(0)) {
+        a.pop();
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    num_theory::Gf,
+    poly::division::{div_rem, trim},
+};
+
+/// Returns the monic greatest common divisor of `a` and `b` by the Euclidean algorithm, without
+/// trailing zeros. The gcd of two zero polynomials is the empty (zero) polynomial.
+///
+/// # Complexity
+/// Time: O(nm), where n = a.len() and m = b.len().
+pub fn poly_gcd<const P: u32>(a: &[Gf<P>], b: &[Gf<P>]) -> Vec<Gf<P>> {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    trim(&mut a);
+    trim(&mut b);
+    while !b.is_empty() {
+        let (_, r) = div_rem(&a, &b);
+        a = std::mem::replace(&mut b, r);
+    }
+    if let Some(&lead) = a.last() {
+        let inv = lead.inv();
+        for c in a.iter_mut() {
+            *c *= inv;
+        }
+    }
+    a
+}
+
+/// Returns the resultant of `a` and `b`, which is zero iff they share a root in the algebraic
+/// closure or either is zero. Follows the Euclidean algorithm using
+/// `Res(a, b) = (-1)^{deg a deg b} lc(b)^{deg a - deg r} Res(b, r)` for `r = a mod b`.
+///
+/// # Complexity
+/// Time: O(nm), where n = a.len() and m = b.len().
+pub fn resultant<const P: u32>(a: &[Gf<P>], b: &[Gf<P>]) -> Gf<P> {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    trim(&mut a);
+    trim(&mut b);
+    if a.is_empty() || b.is_empty() {
+        return Gf::new(0);
+    }
+    let mut res = Gf::new(1);
+    loop {
+        let (m, n) = (a.len() - 1, b.len() - 1);
+        if n == 0 {
+            return res * b[0].pow(m as u64);
+        }
+        let (_, r) = div_rem(&a, &b);
+        if r.is_empty() {
+            return Gf::new(0);
+        }
+        if m * n % 2 == 1 {
+            res = -res;
+        }
+        res *= b[n].pow((m + 1 - r.len()) as u64);
+        a = std::mem::replace(&mut b, r);
+    }
+}
@@ -0,0 +1,7 @@
+mod division;
+mod gcd;
+mod multipoint;
+
+pub use division::{div_rem, inv_series};
+pub use gcd::{poly_gcd, resultant};
+pub use multipoint::{interpolate, multipoint_eval};
@@ -0,0 +1,119 @@
+use crate::{
+    conv::ntt::multiply,
+    num_theory::Gf,
+    poly::division::{div_rem, trim},
+};
+
+/// Evaluates `poly` at every point of `xs` by reducing it down the subproduct tree of the points.
+/// `P` must be an NTT-friendly prime.
+///
+/// # Complexity
+/// Time: O(d log d + n log^2 n), where d = poly.len() and n = xs.len().
+pub fn multipoint_eval<const P: u32>(poly: &[Gf<P>], xs: &[Gf<P>]) -> Vec<Gf<P>> {
+    let n = xs.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let tree = SubproductTree::new(xs);
+    let (_, r) = div_rem(poly, &tree.nodes[1]);
+    let mut res = vec![Gf::new(0); n];
+    tree.eval(1, 0, n, r, &mut res);
+    res
+}
+
+/// Returns the coefficients of the unique polynomial of degree less than n passing through
+/// `(xs[i], ys[i])`, without trailing zeros. The points of `xs` must be distinct, and `P` must be
+/// an NTT-friendly prime.
+///
+/// # Complexity
+/// Time: O(n log^2 n)
+pub fn interpolate<const P: u32>(xs: &[Gf<P>], ys: &[Gf<P>]) -> Vec<Gf<P>> {
+    let n = xs.len();
+    debug_assert_eq!(n, ys.len(), "length mismatch: xs={}, ys={}", n, ys.len());
+    if n == 0 {
+        return Vec::new();
+    }
+    let tree = SubproductTree::new(xs);
+    // The weight of each point is `ys[i] / m'(xs[i])` for the product `m` of all `x - xs[j]`.
+    let m = &tree.nodes[1];
+    let dm: Vec<Gf<P>> = (1..m.len()).map(|i| m[i] * Gf::from(i)).collect();
+    let mut w = vec![Gf::new(0); n];
+    tree.eval(1, 0, n, dm, &mut w);
+    for (w, &y) in w.iter_mut().zip(ys) {
+        debug_assert!(*w != Gf::new(0), "points must be distinct");
+        *w = y / *w;
+    }
+    let mut res = tree.combine(1, 0, n, &w);
+    trim(&mut res);
+    res
+}
+
+/// Products of `x - xs[i]` over the ranges of a segment tree, with node `k` covering `l..r` and
+/// its children `2k` and `2k + 1` splitting at `(l + r) / 2`.
+struct SubproductTree<const P: u32> {
+    xs: Vec<Gf<P>>,
+    nodes: Vec<Vec<Gf<P>>>,
+}
+
+/// Ranges up to this length are evaluated by Horner's method instead of further division.
+const LEAF: usize = 32;
+
+impl<const P: u32> SubproductTree<P> {
+    fn new(xs: &[Gf<P>]) -> Self {
+        let mut res = Self {
+            xs: xs.to_vec(),
+            nodes: vec![Vec::new(); xs.len() << 2],
+        };
+        res.build(1, 0, xs.len());
+        res
+    }
+
+    fn build(&mut self, k: usize, l: usize, r: usize) {
+        if r - l == 1 {
+            self.nodes[k] = vec![-self.xs[l], Gf::new(1)];
+            return;
+        }
+        let m = (l + r) >> 1;
+        self.build(k << 1, l, m);
+        self.build((k << 1) + 1, m, r);
+        self.nodes[k] = multiply(self.nodes[k << 1].clone(), self.nodes[(k << 1) + 1].clone());
+    }
+
+    /// Writes `f(xs[i])` for `i` in `l..r`, given `f` already reduced modulo the product of node
+    /// `k`.
+    fn eval(&self, k: usize, l: usize, r: usize, f: Vec<Gf<P>>, out: &mut [Gf<P>]) {
+        if r - l <= LEAF {
+            for (y, &x) in out[l..r].iter_mut().zip(&self.xs[l..r]) {
+                *y = f.iter().rev().fold(Gf::new(0), |acc, &c| acc * x + c);
+            }
+            return;
+        }
+        let m = (l + r) >> 1;
+        let (_, fl) = div_rem(&f, &self.nodes[k << 1]);
+        let (_, fr) = div_rem(&f, &self.nodes[(k << 1) + 1]);
+        self.eval(k << 1, l, m, fl, out);
+        self.eval((k << 1) + 1, m, r, fr, out);
+    }
+
+    /// Returns the sum over `i` in `l..r` of `w[i]` times the product of `x - xs[j]` over
+    /// `j != i` in `l..r`.
+    fn combine(&self, k: usize, l: usize, r: usize, w: &[Gf<P>]) -> Vec<Gf<P>> {
+        if r - l == 1 {
+            return vec![w[l]];
+        }
+        let m = (l + r) >> 1;
+        let a = multiply(
+            self.combine(k << 1, l, m, w),
+            self.nodes[(k << 1) + 1].clone(),
+        );
+        let b = multiply(
+            self.combine((k << 1) + 1, m, r, w),
+            self.nodes[k << 1].clone(),
+        );
+        let (mut a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        for (x, y) in a.iter_mut().zip(b) {
+            *x += y;
+        }
+        a
+    }
+}
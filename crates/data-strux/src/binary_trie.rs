@@ -0,0 +1,190 @@
+/// A binary trie over `BITS`-bit unsigned integers storing a multiset, supporting XOR-extremal and
+/// order-statistic queries. Nodes of erased values are kept and reused by later insertions.
+///
+/// # Complexity
+/// Space: O(q BITS), where q is the number of distinct inserted values.
+#[derive(Clone)]
+pub struct BinaryTrie<const BITS: u32> {
+    // Children of each node, `!0` if absent. Node 0 is the root.
+    children: Vec<[usize; 2]>,
+    // Number of stored values in the subtree of each node.
+    count: Vec<usize>,
+}
+
+impl<const BITS: u32> Default for BinaryTrie<BITS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: u32> BinaryTrie<BITS> {
+    /// Creates an empty binary trie.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new() -> Self {
+        debug_assert!(BITS <= 64, "BITS must be at most 64: BITS={}", BITS);
+        Self {
+            children: vec![[!0; 2]],
+            count: vec![0],
+        }
+    }
+
+    /// Inserts one copy of `x`.
+    ///
+    /// # Complexity
+    /// Time: O(BITS)
+    pub fn insert(&mut self, x: u64) {
+        debug_assert!(
+            BITS == 64 || x >> BITS == 0,
+            "value out of range: x={}, BITS={}",
+            x,
+            BITS
+        );
+        let mut v = 0;
+        self.count[0] += 1;
+        for i in (0..BITS).rev() {
+            let b = (x >> i & 1) as usize;
+            if self.children[v][b] == !0 {
+                self.children[v][b] = self.children.len();
+                self.children.push([!0; 2]);
+                self.count.push(0);
+            }
+            v = self.children[v][b];
+            self.count[v] += 1;
+        }
+    }
+
+    /// Removes one copy of `x` and returns whether it was present.
+    ///
+    /// # Complexity
+    /// Time: O(BITS)
+    pub fn erase(&mut self, x: u64) -> bool {
+        if self.count(x) == 0 {
+            return false;
+        }
+        let mut v = 0;
+        self.count[0] -= 1;
+        for i in (0..BITS).rev() {
+            v = self.children[v][(x >> i & 1) as usize];
+            self.count[v] -= 1;
+        }
+        true
+    }
+
+    /// Returns the number of copies of `x`.
+    ///
+    /// # Complexity
+    /// Time: O(BITS)
+    pub fn count(&self, x: u64) -> usize {
+        if BITS < 64 && x >> BITS != 0 {
+            return 0;
+        }
+        let mut v = 0;
+        for i in (0..BITS).rev() {
+            v = self.children[v][(x >> i & 1) as usize];
+            if v == !0 {
+                return 0;
+            }
+        }
+        self.count[v]
+    }
+
+    /// Returns the minimum of `x ^ y` over stored `y`. If the trie is empty returns `None`.
+    ///
+    /// # Complexity
+    /// Time: O(BITS)
+    pub fn min_xor(&self, x: u64) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut v = 0;
+        let mut res = 0;
+        for i in (0..BITS).rev() {
+            let b = (x >> i & 1) as usize;
+            let c = self.children[v][b];
+            if c != !0 && self.count[c] > 0 {
+                v = c;
+            } else {
+                v = self.children[v][b ^ 1];
+                res |= 1 << i;
+            }
+        }
+        Some(res)
+    }
+
+    /// Returns the maximum of `x ^ y` over stored `y`. If the trie is empty returns `None`.
+    ///
+    /// # Complexity
+    /// Time: O(BITS)
+    pub fn max_xor(&self, x: u64) -> Option<u64> {
+        let mask = if BITS == 64 { !0 } else { (1 << BITS) - 1 };
+        self.min_xor(!x & mask).map(|m| !m & mask)
+    }
+
+    /// Returns the `k`-th smallest (0-indexed) stored value, counting copies. If `k >= len`
+    /// returns `None`.
+    ///
+    /// # Complexity
+    /// Time: O(BITS)
+    pub fn kth_smallest(&self, mut k: usize) -> Option<u64> {
+        if k >= self.len() {
+            return None;
+        }
+        let mut v = 0;
+        let mut res = 0;
+        for i in (0..BITS).rev() {
+            let c = self.children[v][0];
+            let left = if c == !0 { 0 } else { self.count[c] };
+            if k < left {
+                v = c;
+            } else {
+                k -= left;
+                v = self.children[v][1];
+                res |= 1 << i;
+            }
+        }
+        Some(res)
+    }
+
+    /// Returns the number of stored values less than `x`, counting copies.
+    ///
+    /// # Complexity
+    /// Time: O(BITS)
+    pub fn count_less(&self, x: u64) -> usize {
+        if BITS < 64 && x >> BITS != 0 {
+            return self.len();
+        }
+        let mut v = 0;
+        let mut res = 0;
+        for i in (0..BITS).rev() {
+            let b = (x >> i & 1) as usize;
+            if b == 1 && self.children[v][0] != !0 {
+                res += self.count[self.children[v][0]];
+            }
+            v = self.children[v][b];
+            if v == !0 {
+                break;
+            }
+        }
+        res
+    }
+
+    /// Returns the number of stored values, counting copies.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.count[0]
+    }
+
+    /// Returns whether the trie is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.count[0] == 0
+    }
+}
@@ -0,0 +1,135 @@
+/// A monotone convex hull trick over lines `y = ax + b`, keeping the lower envelope (or the upper
+/// one when created by `new_max`) in a deque.
+///
+/// Lines must be added in order of slope: non-increasing for minimum, non-decreasing for maximum.
+/// Queries must be asked in non-decreasing order of `x`, since lines that stop being optimal are
+/// dropped from the front. Lines and queries may be interleaved.
+///
+/// # Complexity
+/// Space: O(n)
+#[derive(Clone)]
+pub struct ConvexHullTrick {
+    // Lines of the lower envelope, stored negated when maximizing.
+    lines: std::collections::VecDeque<(i64, i64)>,
+    max: bool,
+    last_x: i64,
+}
+
+impl Default for ConvexHullTrick {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConvexHullTrick {
+    /// Creates an empty convex hull trick answering minimum queries.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new() -> Self {
+        Self {
+            lines: std::collections::VecDeque::new(),
+            max: false,
+            last_x: i64::MIN,
+        }
+    }
+
+    /// Creates an empty convex hull trick answering maximum queries.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new_max() -> Self {
+        Self {
+            max: true,
+            ..Self::new()
+        }
+    }
+
+    /// Adds the line `y = slope * x + intercept`.
+    ///
+    /// # Complexity
+    /// Time: amortized O(1)
+    pub fn add_line(&mut self, slope: i64, intercept: i64) {
+        let (a, b) = if self.max {
+            (-slope, -intercept)
+        } else {
+            (slope, intercept)
+        };
+        if let Some(&(a2, b2)) = self.lines.back() {
+            debug_assert!(
+                a <= a2,
+                "slopes must be {}: slope={}",
+                if self.max {
+                    "non-decreasing"
+                } else {
+                    "non-increasing"
+                },
+                slope,
+            );
+            if a == a2 {
+                if b2 <= b {
+                    return;
+                }
+                self.lines.pop_back();
+            }
+        }
+        while self.lines.len() >= 2 {
+            let (a1, b1) = self.lines[self.lines.len() - 2];
+            let (a2, b2) = self.lines[self.lines.len() - 1];
+            // The middle line is useless if the new line overtakes the first no later than it does.
+            if (b as i128 - b1 as i128) * (a1 as i128 - a2 as i128)
+                <= (b2 as i128 - b1 as i128) * (a1 as i128 - a as i128)
+            {
+                self.lines.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.lines.push_back((a, b));
+    }
+
+    /// Returns the minimum (or maximum) of `slope * x + intercept` over all added lines. If no line
+    /// was added returns `None`.
+    ///
+    /// # Complexity
+    /// Time: amortized O(1)
+    pub fn query(&mut self, x: i64) -> Option<i64> {
+        debug_assert!(
+            self.last_x <= x,
+            "queries must be non-decreasing: x={}, last={}",
+            x,
+            self.last_x,
+        );
+        self.last_x = x;
+        while self.lines.len() >= 2 {
+            let (a0, b0) = self.lines[0];
+            let (a1, b1) = self.lines[1];
+            if a0 * x + b0 >= a1 * x + b1 {
+                self.lines.pop_front();
+            } else {
+                break;
+            }
+        }
+        let &(a, b) = self.lines.front()?;
+        let y = a * x + b;
+        Some(if self.max { -y } else { y })
+    }
+
+    /// Returns the number of lines on the envelope.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns whether no line is stored.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
@@ -118,6 +118,16 @@ impl Dsu {
         unsafe { (-self.parent.get_unchecked(root)) as usize }
     }
 
+    /// Returns the members of every set, each in ascending order, with sets ordered by their
+    /// smallest member.
+    ///
+    /// # Complexity
+    /// Time: O(n α(n)), where α is the inverse Ackermann function.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let n = self.len();
+        super::groups(n, |x| self.root(x))
+    }
+
     /// Returns the number of disjoint sets.
     ///
     /// # Complexity
@@ -1,5 +1,25 @@
 mod base;
+mod monoid;
+mod persistent;
 mod potential;
 
 pub use base::Dsu;
+pub use monoid::MonoidDsu;
+pub use persistent::PersistentDsu;
 pub use potential::PotentialDsu;
+
+/// Groups `0..n` by `leader`, each group in ascending order, with groups ordered by their smallest
+/// member.
+pub(crate) fn groups(n: usize, mut leader: impl FnMut(usize) -> usize) -> Vec<Vec<usize>> {
+    let mut id = vec![!0; n];
+    let mut res: Vec<Vec<usize>> = Vec::new();
+    for x in 0..n {
+        let root = leader(x);
+        if id[root] == !0 {
+            id[root] = res.len();
+            res.push(Vec::new());
+        }
+        res[id[root]].push(x);
+    }
+    res
+}
@@ -0,0 +1,134 @@
+use crate::persistent_array::PersistentArray;
+
+/// A fully persistent disjoint set union (DSU) data structure over a persistent array.
+/// Uses union by size without path compression, so every find walks O(log n) parents. Every
+/// union creates a new version and leaves all older versions intact. Version 0 has every element
+/// in its own set.
+///
+/// # Complexity
+/// Space: O(n + q log n), where q is the number of unions.
+pub struct PersistentDsu {
+    /// If negative, this node is a root and the absolute value is the size of the set.
+    /// If non-negative, this is the index of the parent node.
+    parent: PersistentArray<i32>,
+    // The version of `parent` and the number of sets of each version.
+    versions: Vec<(usize, usize)>,
+}
+
+impl PersistentDsu {
+    /// Creates a new persistent DSU with `n` elements, where each element is initially in its own
+    /// set, as version 0.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn new(n: usize) -> Self {
+        debug_assert!(n < (1 << 31), "n must be less than 1<<31, n={}", n);
+        Self {
+            parent: PersistentArray::from_vec(vec![-1; n]),
+            versions: vec![(0, n)],
+        }
+    }
+
+    /// Returns the representative (root) of the set containing `x` in `version`.
+    ///
+    /// # Complexity
+    /// Time: O(log^2 n)
+    pub fn root(&self, version: usize, mut x: usize) -> usize {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        debug_assert!(
+            x < self.len(),
+            "index out of bounds: x={}, len={}",
+            x,
+            self.len()
+        );
+        let v = self.versions[version].0;
+        loop {
+            let p = *self.parent.get(v, x);
+            if p < 0 {
+                return x;
+            }
+            x = p as usize;
+        }
+    }
+
+    /// Creates a new version equal to `version` except that the sets containing `x` and `y` are
+    /// united, and returns it. If they are already the same set, the new version equals
+    /// `version`.
+    ///
+    /// # Complexity
+    /// Time: O(log^2 n)
+    pub fn unite(&mut self, version: usize, x: usize, y: usize) -> usize {
+        let mut rx = self.root(version, x);
+        let mut ry = self.root(version, y);
+        let (v, count) = self.versions[version];
+        if rx == ry {
+            self.versions.push((v, count));
+        } else {
+            let mut sx = *self.parent.get(v, rx);
+            let mut sy = *self.parent.get(v, ry);
+            if sx > sy {
+                std::mem::swap(&mut rx, &mut ry);
+                std::mem::swap(&mut sx, &mut sy);
+            }
+            let v = self.parent.set(v, rx, sx + sy);
+            let v = self.parent.set(v, ry, rx as i32);
+            self.versions.push((v, count - 1));
+        }
+        self.versions.len() - 1
+    }
+
+    /// Returns whether `x` and `y` are in the same set in `version`.
+    ///
+    /// # Complexity
+    /// Time: O(log^2 n)
+    pub fn same(&self, version: usize, x: usize, y: usize) -> bool {
+        self.root(version, x) == self.root(version, y)
+    }
+
+    /// Returns the size of the set containing `x` in `version`.
+    ///
+    /// # Complexity
+    /// Time: O(log^2 n)
+    pub fn set_size(&self, version: usize, x: usize) -> usize {
+        let root = self.root(version, x);
+        -*self.parent.get(self.versions[version].0, root) as usize
+    }
+
+    /// Returns the number of disjoint sets in `version`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn num_sets(&self, version: usize) -> usize {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        self.versions[version].1
+    }
+
+    /// Returns the number of versions created so far, including version 0.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn num_versions(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Returns the total number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+}
@@ -148,6 +148,16 @@ impl<S: PartialEq + Group> PotentialDsu<S> {
         unsafe { (-self.parent.get_unchecked(root)) as usize }
     }
 
+    /// Returns the members of every set, each in ascending order, with sets ordered by their
+    /// smallest member.
+    ///
+    /// # Complexity
+    /// Time: O(n α(n)), where α is the inverse Ackermann function.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let n = self.len();
+        super::groups(n, |x| self.root(x).0)
+    }
+
     /// Returns the number of disjoint sets.
     ///
     /// # Complexity
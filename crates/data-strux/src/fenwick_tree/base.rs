@@ -213,3 +213,36 @@ impl<S: AbelianGroup> FenwickTree<S> {
         self.0.len() == 1
     }
 }
+
+impl<S: AbelianGroup + Ord> FenwickTree<S> {
+    /// Returns the smallest `i` such that `op(a[0], ..., a[i]) >= target`, or `n` if there is no
+    /// such `i`. The prefix folds must be non-decreasing, for example when every element is a
+    /// non-negative count, so that treating the tree as a multiset of indices the element of
+    /// rank `k` (0-indexed) is `lower_bound(k + 1)`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn lower_bound(&self, target: S) -> usize {
+        let n = self.len();
+        if n == 0 {
+            return 0;
+        }
+        let mut pos = 0;
+        let mut acc = S::id();
+        let mut step = 1 << n.ilog2();
+        unsafe {
+            let d = self.0.as_ptr();
+            while step > 0 {
+                if pos + step <= n {
+                    let next = S::op(&acc, &*d.add(pos + step));
+                    if next < target {
+                        pos += step;
+                        acc = next;
+                    }
+                }
+                step >>= 1;
+            }
+        }
+        pos
+    }
+}
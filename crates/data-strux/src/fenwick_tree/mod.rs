@@ -0,0 +1,5 @@
+mod base;
+mod range;
+
+pub use base::FenwickTree;
+pub use range::RangeFenwickTree;
@@ -0,0 +1,151 @@
+use algebrae::algebra::AbelianGroup;
+
+use crate::fenwick_tree::FenwickTree;
+
+/// A fenwick tree supporting range add and range sum, keeping the difference array `d` of the
+/// values in two fenwick trees: one of `d[i]` and one of `i d[i]`. Then
+/// `a[0] + ... + a[r - 1] = r (d[0] + ... + d[r - 1]) - (0 d[0] + ... + (r - 1) d[r - 1])`.
+///
+/// # Complexity
+/// Space: O(n)
+pub struct RangeFenwickTree<S: AbelianGroup> {
+    diff: FenwickTree<S>,
+    weighted: FenwickTree<S>,
+}
+
+impl<S: AbelianGroup> RangeFenwickTree<S> {
+    /// Creates a new range fenwick tree with `n` elements, where all initialized to `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn new(n: usize) -> Self {
+        Self {
+            diff: FenwickTree::new(n),
+            weighted: FenwickTree::new(n),
+        }
+    }
+
+    /// Creates a range fenwick tree from a slice.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn from_slice(v: &[S]) -> Self {
+        let mut diff = Vec::with_capacity(v.len());
+        let mut weighted = Vec::with_capacity(v.len());
+        let mut prev = S::id();
+        for (i, x) in v.iter().enumerate() {
+            let d = S::op(x, &prev.inv());
+            weighted.push(times(&d, i));
+            diff.push(d);
+            prev = x.clone();
+        }
+        Self {
+            diff: FenwickTree::from_vec(diff),
+            weighted: FenwickTree::from_vec(weighted),
+        }
+    }
+
+    /// Sets `a[i]` to `op(a[i], x)` for all `i` in the given range.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn range_add(&mut self, range: impl std::ops::RangeBounds<usize>, x: S) {
+        let (l, r) = self.bounds(range);
+        if l == r {
+            return;
+        }
+        self.diff.operate(l, x.clone());
+        self.weighted.operate(l, times(&x, l));
+        if r < self.len() {
+            self.diff.operate(r, x.inv());
+            self.weighted.operate(r, times(&x, r).inv());
+        }
+    }
+
+    /// Returns `op(a[0], .., a[r - 1])`. When the range is empty, returns `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn prefix_sum(&self, r: usize) -> S {
+        debug_assert!(
+            r <= self.len(),
+            "index out of bounds: r={}, len={}",
+            r,
+            self.len(),
+        );
+        S::op(
+            &times(&self.diff.prefix_fold(r), r),
+            &self.weighted.prefix_fold(r).inv(),
+        )
+    }
+
+    /// Returns `op(a[l], ..., a[r - 1])`. When range is empty, returns `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn range_sum(&self, range: impl std::ops::RangeBounds<usize>) -> S {
+        let (l, r) = self.bounds(range);
+        if l == r {
+            return S::id();
+        }
+        S::op(&self.prefix_sum(l).inv(), &self.prefix_sum(r))
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.diff.len()
+    }
+
+    /// Returns whether the range fenwick tree is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.diff.is_empty()
+    }
+
+    fn bounds(&self, range: impl std::ops::RangeBounds<usize>) -> (usize, usize) {
+        let l = match range.start_bound() {
+            std::ops::Bound::Unbounded => 0,
+            std::ops::Bound::Included(&x) => x,
+            std::ops::Bound::Excluded(&x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Unbounded => self.len(),
+            std::ops::Bound::Included(&x) => x + 1,
+            std::ops::Bound::Excluded(&x) => x,
+        };
+        debug_assert!(
+            l <= r,
+            "left bound must be less than or equal to right bound: l={}, r={}",
+            l,
+            r,
+        );
+        debug_assert!(
+            r <= self.len(),
+            "index out of bounds: r={}, len={}",
+            r,
+            self.len(),
+        );
+        (l, r)
+    }
+}
+
+/// Returns `x` operated with itself `k` times, by doubling.
+fn times<S: AbelianGroup>(x: &S, mut k: usize) -> S {
+    let mut res = S::id();
+    let mut x = x.clone();
+    while k > 0 {
+        if k & 1 == 1 {
+            res = S::op(&res, &x);
+        }
+        x = S::op(&x, &x);
+        k >>= 1;
+    }
+    res
+}
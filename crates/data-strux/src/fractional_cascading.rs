@@ -0,0 +1,96 @@
+/// A fractional cascading structure over a chain of sorted lists that locates a value in every
+/// list with one binary search. Each level stores its own list merged with every other element of
+/// the level below, so the position found on one level determines the position on the next up to
+/// a single comparison.
+///
+/// # Complexity
+/// Space: O(N), where N is the total length of the lists.
+pub struct FractionalCascading<T: Ord + Clone> {
+    // Level `i` is the list `i` merged with the odd-indexed elements of level `i + 1`.
+    keys: Box<[Vec<T>]>,
+    // `own[i][p]` and `cascaded[i][p]` count the elements of `keys[i][..p]` coming from list `i`
+    // and from level `i + 1` respectively.
+    own: Box<[Vec<usize>]>,
+    cascaded: Box<[Vec<usize>]>,
+}
+
+impl<T: Ord + Clone> FractionalCascading<T> {
+    /// Creates a fractional cascading structure from sorted lists. Building merges each list
+    /// with half of the level below, so the total size of all levels is at most 2N.
+    ///
+    /// # Complexity
+    /// Time: O(N + L), where L is the number of lists.
+    pub fn from_vec(lists: Vec<Vec<T>>) -> Self {
+        let l = lists.len();
+        debug_assert!(l > 0, "lists must not be empty");
+        let mut keys: Vec<Vec<T>> = vec![Vec::new(); l];
+        let mut own = vec![Vec::new(); l];
+        let mut cascaded = vec![Vec::new(); l];
+        for (i, list) in lists.into_iter().enumerate().rev() {
+            debug_assert!(
+                list.is_sorted(),
+                "list must be sorted in ascending order: i={}",
+                i
+            );
+            let below: Vec<T> = keys
+                .get(i + 1)
+                .map(|k| k.iter().skip(1).step_by(2).cloned().collect())
+                .unwrap_or_default();
+            let n = list.len() + below.len();
+            let (mut merged, mut o, mut c) =
+                (Vec::with_capacity(n), vec![0; n + 1], vec![0; n + 1]);
+            let (mut a, mut b) = (list.into_iter().peekable(), below.into_iter().peekable());
+            for p in 0..n {
+                let from_own = match (a.peek(), b.peek()) {
+                    (Some(x), Some(y)) => x <= y,
+                    (x, _) => x.is_some(),
+                };
+                merged.push(if from_own { a.next() } else { b.next() }.unwrap());
+                o[p + 1] = o[p] + from_own as usize;
+                c[p + 1] = c[p] + !from_own as usize;
+            }
+            keys[i] = merged;
+            own[i] = o;
+            cascaded[i] = c;
+        }
+        Self {
+            keys: keys.into_boxed_slice(),
+            own: own.into_boxed_slice(),
+            cascaded: cascaded.into_boxed_slice(),
+        }
+    }
+
+    /// Returns, for every list, the number of its elements less than `x`, that is the index of
+    /// the first element not less than `x`.
+    ///
+    /// # Complexity
+    /// Time: O(log N + L)
+    pub fn lower_bound(&self, x: &T) -> Vec<usize> {
+        let l = self.keys.len();
+        let mut res = Vec::with_capacity(l);
+        let mut p = self.keys[0].partition_point(|y| y < x);
+        for i in 0..l {
+            res.push(self.own[i][p]);
+            if i + 1 < l {
+                // The cascaded elements below `x` are `keys[i + 1][1], [3], .., [2c - 1]`, so
+                // only `keys[i + 1][2c]` remains undecided.
+                let below = &self.keys[i + 1];
+                p = self.cascaded[i][p] << 1;
+                if p < below.len() && below[p] < *x {
+                    p += 1;
+                }
+            }
+        }
+        res
+    }
+
+    /// Returns the number of lists.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[allow(clippy::len_without_is_empty)]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+}
@@ -1,4 +1,12 @@
+pub mod binary_trie;
+pub mod convex_hull_trick;
 pub mod disjoint_set;
 pub mod fenwick_tree;
+pub mod fractional_cascading;
+pub mod mergeable_heap;
+pub mod ops;
+pub mod persistent_array;
+pub mod range_mode;
+pub mod range_xor_basis;
 pub mod segment_tree;
 pub mod sparse_table;
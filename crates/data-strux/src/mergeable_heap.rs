@@ -0,0 +1,127 @@
+/// A leftist heap, a min-heap supporting melding two heaps. The rank of a node is the length of
+/// its right spine, and every left child has rank at least that of its sibling, so right spines
+/// have length O(log n).
+///
+/// # Complexity
+/// Space: O(n)
+pub struct MergeableHeap<T: Ord> {
+    root: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+struct Node<T> {
+    value: T,
+    rank: usize,
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+}
+
+impl<T: Ord> Default for MergeableHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> MergeableHeap<T> {
+    /// Creates an empty heap.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Inserts `x`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn push(&mut self, x: T) {
+        let node = Box::new(Node {
+            value: x,
+            rank: 1,
+            left: None,
+            right: None,
+        });
+        self.root = merge(self.root.take(), Some(node));
+        self.len += 1;
+    }
+
+    /// Returns the minimum element, or `None` if the heap is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn peek_min(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.value)
+    }
+
+    /// Removes and returns the minimum element, or `None` if the heap is empty.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn pop_min(&mut self) -> Option<T> {
+        let node = *self.root.take()?;
+        self.root = merge(node.left, node.right);
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    /// Moves all elements of `other` into this heap.
+    ///
+    /// # Complexity
+    /// Time: O(log n + log m), where m = other.len().
+    pub fn meld(&mut self, mut other: Self) {
+        self.root = merge(self.root.take(), other.root.take());
+        self.len += other.len;
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the heap is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Ord> Drop for MergeableHeap<T> {
+    // Left spines may be long, so nodes are freed without recursion.
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+#[inline(always)]
+fn rank<T>(node: &Option<Box<Node<T>>>) -> usize {
+    node.as_ref().map_or(0, |node| node.rank)
+}
+
+fn merge<T: Ord>(a: Option<Box<Node<T>>>, b: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
+    match (a, b) {
+        (None, x) | (x, None) => x,
+        (Some(mut a), Some(mut b)) => {
+            if b.value < a.value {
+                std::mem::swap(&mut a, &mut b);
+            }
+            a.right = merge(a.right.take(), Some(b));
+            if rank(&a.left) < rank(&a.right) {
+                std::mem::swap(&mut a.left, &mut a.right);
+            }
+            a.rank = rank(&a.right) + 1;
+            Some(a)
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use algebrae::algebra::{Action, Monoid};
+
+/// A monoid of range sums that also tracks the number of elements, so that actions depending on
+/// the length (such as adding to every element) can be applied to a whole segment.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SumLen {
+    pub sum: i64,
+    pub len: i64,
+}
+
+impl SumLen {
+    /// Creates a single element `x`.
+    #[inline(always)]
+    pub fn new(x: i64) -> Self {
+        Self { sum: x, len: 1 }
+    }
+}
+
+impl Monoid for SumLen {
+    #[inline(always)]
+    fn id() -> Self {
+        Self { sum: 0, len: 0 }
+    }
+    #[inline(always)]
+    fn op(&self, rhs: &Self) -> Self {
+        Self {
+            sum: self.sum + rhs.sum,
+            len: self.len + rhs.len,
+        }
+    }
+}
+
+/// An action on `SumLen` mapping every element `x` to `assign.unwrap_or(x) + add`. In `op`,
+/// `self` is the later action: a later assignment discards everything before it, while a later
+/// addition keeps the earlier assignment and accumulates onto its addition.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AssignAdd {
+    pub assign: Option<i64>,
+    pub add: i64,
+}
+
+impl AssignAdd {
+    /// Creates the action setting every element to `x`.
+    #[inline(always)]
+    pub fn assign(x: i64) -> Self {
+        Self {
+            assign: Some(x),
+            add: 0,
+        }
+    }
+
+    /// Creates the action adding `x` to every element.
+    #[inline(always)]
+    pub fn add(x: i64) -> Self {
+        Self {
+            assign: None,
+            add: x,
+        }
+    }
+}
+
+impl Monoid for AssignAdd {
+    #[inline(always)]
+    fn id() -> Self {
+        Self::add(0)
+    }
+    #[inline(always)]
+    fn op(&self, rhs: &Self) -> Self {
+        if self.assign.is_some() {
+            *self
+        } else {
+            Self {
+                assign: rhs.assign,
+                add: rhs.add + self.add,
+            }
+        }
+    }
+}
+
+impl Action<SumLen> for AssignAdd {
+    #[inline(always)]
+    fn act(&self, s: &SumLen) -> SumLen {
+        let sum = match self.assign {
+            Some(x) => x * s.len,
+            None => s.sum,
+        };
+        SumLen {
+            sum: sum + self.add * s.len,
+            len: s.len,
+        }
+    }
+}
+
+/// A monoid of range sums that also tracks the number of elements and the sum of their positions,
+/// so that actions depending on the position (such as adding an arithmetic progression) can be
+/// applied to a whole segment.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SumIdx {
+    pub sum: i64,
+    pub len: i64,
+    pub idx_sum: i64,
+}
+
+impl SumIdx {
+    /// Creates a single element `x` at position `i`.
+    #[inline(always)]
+    pub fn new(i: usize, x: i64) -> Self {
+        Self {
+            sum: x,
+            len: 1,
+            idx_sum: i as i64,
+        }
+    }
+}
+
+impl Monoid for SumIdx {
+    #[inline(always)]
+    fn id() -> Self {
+        Self {
+            sum: 0,
+            len: 0,
+            idx_sum: 0,
+        }
+    }
+    #[inline(always)]
+    fn op(&self, rhs: &Self) -> Self {
+        Self {
+            sum: self.sum + rhs.sum,
+            len: self.len + rhs.len,
+            idx_sum: self.idx_sum + rhs.idx_sum,
+        }
+    }
+}
+
+/// An action on `SumIdx` adding `base + step * i` to the element at position `i`. Adding the
+/// progression `a, a + d, a + 2d, ...` to a range starting at `l` is `AddProgression::new(l, a, d)`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AddProgression {
+    pub base: i64,
+    pub step: i64,
+}
+
+impl AddProgression {
+    /// Creates the action adding `a + d * (i - l)` to the element at position `i`.
+    #[inline(always)]
+    pub fn new(l: usize, a: i64, d: i64) -> Self {
+        Self {
+            base: a - d * l as i64,
+            step: d,
+        }
+    }
+}
+
+impl Monoid for AddProgression {
+    #[inline(always)]
+    fn id() -> Self {
+        Self { base: 0, step: 0 }
+    }
+    #[inline(always)]
+    fn op(&self, rhs: &Self) -> Self {
+        Self {
+            base: self.base + rhs.base,
+            step: self.step + rhs.step,
+        }
+    }
+}
+
+impl Action<SumIdx> for AddProgression {
+    #[inline(always)]
+    fn act(&self, s: &SumIdx) -> SumIdx {
+        SumIdx {
+            sum: s.sum + self.base * s.len + self.step * s.idx_sum,
+            len: s.len,
+            idx_sum: s.idx_sum,
+        }
+    }
+}
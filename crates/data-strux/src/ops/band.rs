@@ -0,0 +1,34 @@
+use algebrae::{algebra::Band, num_theory::gcd};
+
+/// A band under greatest common divisor.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Gcd(pub u64);
+
+impl Band for Gcd {
+    #[inline(always)]
+    fn op(&self, other: &Self) -> Self {
+        Self(gcd(self.0, other.0))
+    }
+}
+
+/// A band under bitwise AND.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BitAnd(pub u64);
+
+impl Band for BitAnd {
+    #[inline(always)]
+    fn op(&self, other: &Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+/// A band under bitwise OR.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BitOr(pub u64);
+
+impl Band for BitOr {
+    #[inline(always)]
+    fn op(&self, other: &Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
@@ -0,0 +1,40 @@
+use algebrae::algebra::{AbelianGroup, Group};
+
+/// A group under addition of signed numbers, such as `AddGroup<i64>` for range sums in a
+/// `FenwickTree`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct AddGroup<T>(pub T);
+
+impl<T: Copy + Default + std::ops::Add<Output = T> + std::ops::Neg<Output = T>> Group
+    for AddGroup<T>
+{
+    #[inline(always)]
+    fn id() -> Self {
+        Self(T::default())
+    }
+    #[inline(always)]
+    fn op(&self, rhs: &Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+    #[inline(always)]
+    fn inv(&self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<T: Copy + Default + std::ops::Add<Output = T> + std::ops::Neg<Output = T>> AbelianGroup
+    for AddGroup<T>
+{
+    #[inline(always)]
+    fn id() -> Self {
+        Self(T::default())
+    }
+    #[inline(always)]
+    fn op(&self, rhs: &Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+    #[inline(always)]
+    fn inv(&self) -> Self {
+        Self(-self.0)
+    }
+}
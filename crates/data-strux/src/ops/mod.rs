@@ -0,0 +1,7 @@
+mod action;
+mod band;
+mod group;
+
+pub use action::{AddProgression, AssignAdd, SumIdx, SumLen};
+pub use band::{BitAnd, BitOr, Gcd};
+pub use group::AddGroup;
@@ -0,0 +1,143 @@
+/// A fully persistent array backed by a balanced binary tree with path copying. Every update
+/// creates a new version and leaves all older versions intact. Version 0 is the initial array.
+///
+/// # Complexity
+/// Space: O(n + q log n), where q is the number of updates.
+pub struct PersistentArray<T: Clone> {
+    n: usize,
+    // Branches hold their children, and leaves hold `[index into values, !0]`.
+    nodes: Vec<[usize; 2]>,
+    values: Vec<T>,
+    roots: Vec<usize>,
+}
+
+impl<T: Clone> PersistentArray<T> {
+    /// Creates a persistent array from a vec as version 0.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn from_vec(v: Vec<T>) -> Self {
+        let n = v.len();
+        debug_assert!(n > 0, "n must not be zero");
+        let mut res = Self {
+            n,
+            nodes: Vec::with_capacity(n << 1),
+            values: v,
+            roots: Vec::new(),
+        };
+        let root = res.build(0, n);
+        res.roots.push(root);
+        res
+    }
+
+    /// Creates a persistent array from a slice as version 0.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn from_slice(v: &[T]) -> Self {
+        Self::from_vec(v.to_vec())
+    }
+
+    fn build(&mut self, l: usize, r: usize) -> usize {
+        if r - l == 1 {
+            self.nodes.push([l, !0]);
+        } else {
+            let m = (l + r) >> 1;
+            let left = self.build(l, m);
+            let right = self.build(m, r);
+            self.nodes.push([left, right]);
+        }
+        self.nodes.len() - 1
+    }
+
+    /// Creates a new version equal to `version` except that index `i` is `x`, and returns it.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn set(&mut self, version: usize, i: usize, x: T) -> usize {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        debug_assert!(i < self.n, "index out of bounds: i={}, len={}", i, self.n,);
+        let mut path = Vec::new();
+        let mut node = self.roots[version];
+        let (mut l, mut r) = (0, self.n);
+        unsafe {
+            let nodes = self.nodes.as_ptr();
+            while r - l > 1 {
+                let m = (l + r) >> 1;
+                let side = (i >= m) as usize;
+                path.push((node, side));
+                node = (*nodes.add(node))[side];
+                if side == 0 {
+                    r = m;
+                } else {
+                    l = m;
+                }
+            }
+        }
+        self.values.push(x);
+        self.nodes.push([self.values.len() - 1, !0]);
+        let mut child = self.nodes.len() - 1;
+        for (node, side) in path.into_iter().rev() {
+            let mut copy = self.nodes[node];
+            copy[side] = child;
+            self.nodes.push(copy);
+            child = self.nodes.len() - 1;
+        }
+        self.roots.push(child);
+        self.roots.len() - 1
+    }
+
+    /// Returns the value at index `i` in `version`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn get(&self, version: usize, i: usize) -> &T {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        debug_assert!(i < self.n, "index out of bounds: i={}, len={}", i, self.n,);
+        let (mut l, mut r) = (0, self.n);
+        unsafe {
+            let nodes = self.nodes.as_ptr();
+            let mut node = *self.roots.get_unchecked(version);
+            while r - l > 1 {
+                let m = (l + r) >> 1;
+                if i < m {
+                    node = (*nodes.add(node))[0];
+                    r = m;
+                } else {
+                    node = (*nodes.add(node))[1];
+                    l = m;
+                }
+            }
+            self.values.get_unchecked((*nodes.add(node))[0])
+        }
+    }
+
+    /// Returns the number of versions created so far, including version 0.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn num_versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.n
+    }
+}
@@ -0,0 +1,146 @@
+/// A static structure answering range mode queries (the most frequent value in a range) by sqrt
+/// decomposition. The modes of every run of whole blocks are precomputed, and a query extends the
+/// mode of its inner blocks by the elements of the two partial blocks, using the position lists of
+/// each value to test whether a frequency can be raised.
+///
+/// # Complexity
+/// Space: O(n)
+pub struct RangeMode<T: Ord + Clone> {
+    values: Box<[T]>,
+    ids: Box<[usize]>,
+    // `occ[ids[i]][rank[i]] == i`.
+    rank: Box<[usize]>,
+    occ: Box<[Vec<usize>]>,
+    block: usize,
+    num_blocks: usize,
+    // `modes[i * num_blocks + j]` is `(id, frequency)` of the mode of blocks `i..=j`.
+    modes: Box<[(usize, usize)]>,
+}
+
+impl<T: Ord + Clone> RangeMode<T> {
+    /// Creates a range mode structure from a slice.
+    ///
+    /// # Complexity
+    /// Time: O(n sqrt(n))
+    pub fn from_slice(v: &[T]) -> Self {
+        let n = v.len();
+        debug_assert!(n > 0, "range mode must not be empty");
+        let mut values = v.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        let mut occ = vec![Vec::new(); values.len()];
+        let mut ids = Vec::with_capacity(n);
+        let mut rank = Vec::with_capacity(n);
+        for (i, x) in v.iter().enumerate() {
+            let id = values.binary_search(x).unwrap();
+            ids.push(id);
+            rank.push(occ[id].len());
+            occ[id].push(i);
+        }
+
+        let block = n.isqrt().max(1);
+        let num_blocks = n.div_ceil(block);
+        let mut modes = vec![(0, 0); num_blocks * num_blocks];
+        let mut cnt = vec![0; values.len()];
+        for i in 0..num_blocks {
+            let mut best = (0, 0);
+            for j in i..num_blocks {
+                for &id in ids[j * block..((j + 1) * block).min(n)].iter() {
+                    cnt[id] += 1;
+                    if cnt[id] > best.1 {
+                        best = (id, cnt[id]);
+                    }
+                }
+                modes[i * num_blocks + j] = best;
+            }
+            for &id in ids[i * block..].iter() {
+                cnt[id] = 0;
+            }
+        }
+
+        Self {
+            values: values.into_boxed_slice(),
+            ids: ids.into_boxed_slice(),
+            rank: rank.into_boxed_slice(),
+            occ: occ.into_boxed_slice(),
+            block,
+            num_blocks,
+            modes: modes.into_boxed_slice(),
+        }
+    }
+
+    /// Creates a range mode structure from a vec.
+    ///
+    /// # Complexity
+    /// Time: O(n sqrt(n))
+    pub fn from_vec(v: Vec<T>) -> Self {
+        Self::from_slice(&v)
+    }
+
+    /// Returns a most frequent value in `range` and its frequency. When several values are tied,
+    /// any one of them may be returned.
+    ///
+    /// # Complexity
+    /// Time: O(sqrt(n))
+    pub fn mode(&self, range: impl std::ops::RangeBounds<usize>) -> (&T, usize) {
+        let l = match range.start_bound() {
+            std::ops::Bound::Unbounded => 0,
+            std::ops::Bound::Included(&x) => x,
+            std::ops::Bound::Excluded(&x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Unbounded => self.len(),
+            std::ops::Bound::Included(&x) => x + 1,
+            std::ops::Bound::Excluded(&x) => x,
+        };
+        debug_assert!(
+            l < r,
+            "left bound must be less than right bound: l={}, r={}",
+            l,
+            r,
+        );
+        debug_assert!(
+            r <= self.len(),
+            "index out of bounds: r={}, len={}",
+            r,
+            self.len(),
+        );
+        let (bl, br) = (l.div_ceil(self.block), r / self.block);
+        let (mut best, head, tail) = if bl < br {
+            (
+                self.modes[bl * self.num_blocks + br - 1],
+                bl * self.block,
+                br * self.block,
+            )
+        } else {
+            ((self.ids[l], 0), r, r)
+        };
+        // An element left of the inner blocks raises the frequency if its value occurs again
+        // `best.1` positions later within the range, and symmetrically on the right.
+        for i in l..head {
+            let (id, k) = (self.ids[i], self.rank[i]);
+            let occ = &self.occ[id];
+            while k + best.1 < occ.len() && occ[k + best.1] < r {
+                best = (id, best.1 + 1);
+            }
+        }
+        for i in tail..r {
+            let (id, k) = (self.ids[i], self.rank[i]);
+            let occ = &self.occ[id];
+            while k >= best.1 && occ[k - best.1] >= l {
+                best = (id, best.1 + 1);
+            }
+        }
+        (&self.values[best.0], best.1)
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[allow(clippy::len_without_is_empty)]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+}
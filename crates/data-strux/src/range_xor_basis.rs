@@ -0,0 +1,96 @@
+/// A structure answering the maximum XOR of a subset of a range. For every prefix it keeps a
+/// linear basis over GF(2) where each vector remembers the index it was inserted from, preferring
+/// later indices, so that the vectors usable for a range `l..r` are those of prefix `r` whose
+/// index is at least `l`.
+///
+/// # Complexity
+/// Space: O(n w), where w is the bit width of the largest value.
+pub struct RangeXorBasis {
+    n: usize,
+    w: usize,
+    // Basis of prefix r at data[r * w..(r + 1) * w], indexed by leading bit.
+    data: Vec<(u64, usize)>,
+}
+
+impl RangeXorBasis {
+    /// Creates a new structure from a slice.
+    ///
+    /// # Complexity
+    /// Time: O(n w)
+    pub fn from_slice(v: &[u64]) -> Self {
+        let n = v.len();
+        let w = (u64::BITS - v.iter().fold(0, |acc, &x| acc | x).leading_zeros()) as usize;
+        let mut data = vec![(0, 0); (n + 1) * w];
+        for (i, &x) in v.iter().enumerate() {
+            data.copy_within(i * w..(i + 1) * w, (i + 1) * w);
+            let basis = &mut data[(i + 1) * w..(i + 2) * w];
+            let (mut x, mut p) = (x, i);
+            for b in (0..w).rev() {
+                if x >> b & 1 == 0 {
+                    continue;
+                }
+                if basis[b].0 == 0 {
+                    basis[b] = (x, p);
+                    break;
+                }
+                // Keeps the vector from the later index and carries the older one downwards.
+                if basis[b].1 < p {
+                    (x, p) = std::mem::replace(&mut basis[b], (x, p));
+                }
+                x ^= basis[b].0;
+            }
+        }
+        Self { n, w, data }
+    }
+
+    /// Returns the maximum XOR of any subset of the elements in the range, which is `0` for an
+    /// empty range.
+    ///
+    /// # Complexity
+    /// Time: O(w)
+    pub fn max_xor(&self, range: impl std::ops::RangeBounds<usize>) -> u64 {
+        let l = match range.start_bound() {
+            std::ops::Bound::Unbounded => 0,
+            std::ops::Bound::Included(&x) => x,
+            std::ops::Bound::Excluded(&x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Unbounded => self.n,
+            std::ops::Bound::Included(&x) => x + 1,
+            std::ops::Bound::Excluded(&x) => x,
+        };
+        debug_assert!(
+            l <= r,
+            "left bound must not exceed right bound: l={}, r={}",
+            l,
+            r,
+        );
+        debug_assert!(r <= self.n, "index out of bounds: r={}, len={}", r, self.n);
+        let basis = &self.data[r * self.w..(r + 1) * self.w];
+        let mut res = 0;
+        for &(x, p) in basis.iter().rev() {
+            if x != 0 && p >= l && res ^ x > res {
+                res ^= x;
+            }
+        }
+        res
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns whether the sequence is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+}
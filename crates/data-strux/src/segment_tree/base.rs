@@ -1,5 +1,7 @@
 use algebrae::algebra::Monoid;
 
+use crate::segment_tree::cover;
+
 /// A segment tree structure.
 ///
 /// # Complexity
@@ -168,6 +170,83 @@ impl<S: Monoid> SegmentTree<S> {
         S::op(&left, &right)
     }
 
+    /// Returns the largest `r` such that `pred(op(a[l], ..., a[r - 1]))` holds, assuming `pred`
+    /// is monotone (once false, it stays false as the range grows). `pred(&S::id())` must be
+    /// true. When `l == len()` returns `len()`, and when `pred` already fails on `a[l]` returns
+    /// `l`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn max_right<P: Fn(&S) -> bool>(&self, l: usize, pred: P) -> usize {
+        let n = self.len();
+        debug_assert!(l <= n, "index out of bounds: l={}, len={}", l, n);
+        debug_assert!(pred(&S::id()), "pred(id) must be true");
+        if l == n {
+            return n;
+        }
+        let (nodes, len) = cover(n, l, n);
+        let mut acc = S::id();
+        unsafe {
+            let d = self.0.as_ptr();
+            for &k in nodes[..len].iter() {
+                let t = S::op(&acc, &*d.add(k));
+                if pred(&t) {
+                    acc = t;
+                    continue;
+                }
+                let mut k = k;
+                while k < n {
+                    k <<= 1;
+                    let t = S::op(&acc, &*d.add(k));
+                    if pred(&t) {
+                        acc = t;
+                        k += 1;
+                    }
+                }
+                return k - n;
+            }
+        }
+        n
+    }
+
+    /// Returns the smallest `l` such that `pred(op(a[l], ..., a[r - 1]))` holds, assuming `pred`
+    /// is monotone (once false, it stays false as the range grows). `pred(&S::id())` must be
+    /// true. When `r == 0` returns `0`, and when `pred` already fails on `a[r - 1]` returns `r`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn min_left<P: Fn(&S) -> bool>(&self, r: usize, pred: P) -> usize {
+        let n = self.len();
+        debug_assert!(r <= n, "index out of bounds: r={}, len={}", r, n);
+        debug_assert!(pred(&S::id()), "pred(id) must be true");
+        if r == 0 {
+            return 0;
+        }
+        let (nodes, len) = cover(n, 0, r);
+        let mut acc = S::id();
+        unsafe {
+            let d = self.0.as_ptr();
+            for &k in nodes[..len].iter().rev() {
+                let t = S::op(&*d.add(k), &acc);
+                if pred(&t) {
+                    acc = t;
+                    continue;
+                }
+                let mut k = k;
+                while k < n {
+                    k = (k << 1) + 1;
+                    let t = S::op(&*d.add(k), &acc);
+                    if pred(&t) {
+                        acc = t;
+                        k -= 1;
+                    }
+                }
+                return k + 1 - n;
+            }
+        }
+        0
+    }
+
     /// Returns `op(a[0], ..., a[n - 1])`.
     ///
     /// # Complexity
@@ -176,6 +255,14 @@ impl<S: Monoid> SegmentTree<S> {
         unsafe { self.0.get_unchecked(1).clone() }
     }
 
+    /// Returns all elements as a vec.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn to_vec(&self) -> Vec<S> {
+        self.0[self.len()..].to_vec()
+    }
+
     /// Returns the number of elements.
     ///
     /// # Complexity
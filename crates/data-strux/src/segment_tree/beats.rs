@@ -0,0 +1,302 @@
+/// A segment tree beats structure over `i64` supporting range chmin, range chmax, range add and
+/// range sum. Each node keeps its maximum, strict second maximum and the count of the maximum,
+/// and the same for the minimum, so that a chmin only lowering the maxima of a node is applied
+/// there as a tag instead of descending.
+///
+/// # Complexity
+/// Space: O(n)
+pub struct SegmentTreeBeats {
+    n: usize,
+    nodes: Box<[Node]>,
+}
+
+#[derive(Clone, Copy)]
+struct Node {
+    sum: i64,
+    max: i64,
+    max2: i64,
+    max_cnt: i64,
+    min: i64,
+    min2: i64,
+    min_cnt: i64,
+    len: i64,
+    add: i64,
+}
+
+impl Node {
+    fn leaf(x: i64) -> Self {
+        Self {
+            sum: x,
+            max: x,
+            max2: i64::MIN,
+            max_cnt: 1,
+            min: x,
+            min2: i64::MAX,
+            min_cnt: 1,
+            len: 1,
+            add: 0,
+        }
+    }
+
+    fn merge(a: &Self, b: &Self) -> Self {
+        let (max, max2, max_cnt) = match a.max.cmp(&b.max) {
+            std::cmp::Ordering::Greater => (a.max, a.max2.max(b.max), a.max_cnt),
+            std::cmp::Ordering::Less => (b.max, a.max.max(b.max2), b.max_cnt),
+            std::cmp::Ordering::Equal => (a.max, a.max2.max(b.max2), a.max_cnt + b.max_cnt),
+        };
+        let (min, min2, min_cnt) = match a.min.cmp(&b.min) {
+            std::cmp::Ordering::Less => (a.min, a.min2.min(b.min), a.min_cnt),
+            std::cmp::Ordering::Greater => (b.min, a.min.min(b.min2), b.min_cnt),
+            std::cmp::Ordering::Equal => (a.min, a.min2.min(b.min2), a.min_cnt + b.min_cnt),
+        };
+        Self {
+            sum: a.sum + b.sum,
+            max,
+            max2,
+            max_cnt,
+            min,
+            min2,
+            min_cnt,
+            len: a.len + b.len,
+            add: 0,
+        }
+    }
+
+    /// Lowers the maxima to `x`, where `max2 < x < max`.
+    fn chmin(&mut self, x: i64) {
+        self.sum =
+            (self.sum as i128 + (x as i128 - self.max as i128) * self.max_cnt as i128) as i64;
+        if self.min == self.max {
+            self.min = x;
+        } else if self.min2 == self.max {
+            self.min2 = x;
+        }
+        self.max = x;
+    }
+
+    /// Raises the minima to `x`, where `min < x < min2`.
+    fn chmax(&mut self, x: i64) {
+        self.sum =
+            (self.sum as i128 + (x as i128 - self.min as i128) * self.min_cnt as i128) as i64;
+        if self.max == self.min {
+            self.max = x;
+        } else if self.max2 == self.min {
+            self.max2 = x;
+        }
+        self.min = x;
+    }
+
+    fn add(&mut self, x: i64) {
+        self.sum = (self.sum as i128 + x as i128 * self.len as i128) as i64;
+        self.max += x;
+        self.min += x;
+        if self.max2 != i64::MIN {
+            self.max2 += x;
+        }
+        if self.min2 != i64::MAX {
+            self.min2 += x;
+        }
+        self.add += x;
+    }
+}
+
+impl SegmentTreeBeats {
+    /// Creates a new segment tree beats with `n` zeros.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn new(n: usize) -> Self {
+        Self::from_slice(&vec![0; n])
+    }
+
+    /// Creates a new segment tree beats from a slice.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn from_slice(v: &[i64]) -> Self {
+        let n = v.len();
+        debug_assert!(n > 0, "n must not be zero");
+        let mut res = Self {
+            n,
+            nodes: vec![Node::leaf(0); n << 2].into_boxed_slice(),
+        };
+        res.build(1, 0, n, v);
+        res
+    }
+
+    /// Sets `a[i]` to `min(a[i], x)` for all `i` in the given range. If `x` is at least the
+    /// maximum of the range, nothing changes.
+    ///
+    /// # Complexity
+    /// Time: amortized O(log^2 n)
+    pub fn range_chmin(&mut self, range: impl std::ops::RangeBounds<usize>, x: i64) {
+        let (l, r) = self.bounds(range);
+        if l < r {
+            self.chmin(1, 0, self.n, l, r, x);
+        }
+    }
+
+    /// Sets `a[i]` to `max(a[i], x)` for all `i` in the given range. If `x` is at most the
+    /// minimum of the range, nothing changes.
+    ///
+    /// # Complexity
+    /// Time: amortized O(log^2 n)
+    pub fn range_chmax(&mut self, range: impl std::ops::RangeBounds<usize>, x: i64) {
+        let (l, r) = self.bounds(range);
+        if l < r {
+            self.chmax(1, 0, self.n, l, r, x);
+        }
+    }
+
+    /// Adds `x` to `a[i]` for all `i` in the given range.
+    ///
+    /// # Complexity
+    /// Time: amortized O(log^2 n)
+    pub fn range_add(&mut self, range: impl std::ops::RangeBounds<usize>, x: i64) {
+        let (l, r) = self.bounds(range);
+        if l < r {
+            self.add(1, 0, self.n, l, r, x);
+        }
+    }
+
+    /// Returns `a[l] + ... + a[r - 1]`. When range is empty, return `0`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn range_sum(&mut self, range: impl std::ops::RangeBounds<usize>) -> i64 {
+        let (l, r) = self.bounds(range);
+        if l < r {
+            self.sum(1, 0, self.n, l, r)
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns whether the segment tree beats is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn bounds(&self, range: impl std::ops::RangeBounds<usize>) -> (usize, usize) {
+        let l = match range.start_bound() {
+            std::ops::Bound::Unbounded => 0,
+            std::ops::Bound::Included(&x) => x,
+            std::ops::Bound::Excluded(&x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Unbounded => self.n,
+            std::ops::Bound::Included(&x) => x + 1,
+            std::ops::Bound::Excluded(&x) => x,
+        };
+        debug_assert!(
+            l <= r,
+            "left bound must be less than or equal to right bound: l={}, r={}",
+            l,
+            r,
+        );
+        debug_assert!(r <= self.n, "index out of bounds: r={}, len={}", r, self.n);
+        (l, r)
+    }
+
+    fn build(&mut self, k: usize, l: usize, r: usize, v: &[i64]) {
+        if r - l == 1 {
+            self.nodes[k] = Node::leaf(v[l]);
+            return;
+        }
+        let m = (l + r) >> 1;
+        self.build(k << 1, l, m, v);
+        self.build((k << 1) + 1, m, r, v);
+        self.pull(k);
+    }
+
+    fn pull(&mut self, k: usize) {
+        self.nodes[k] = Node::merge(&self.nodes[k << 1], &self.nodes[(k << 1) + 1]);
+    }
+
+    fn push(&mut self, k: usize) {
+        let node = self.nodes[k];
+        for c in [k << 1, (k << 1) + 1] {
+            let child = &mut self.nodes[c];
+            if node.add != 0 {
+                child.add(node.add);
+            }
+            if child.max > node.max {
+                child.chmin(node.max);
+            }
+            if child.min < node.min {
+                child.chmax(node.min);
+            }
+        }
+        self.nodes[k].add = 0;
+    }
+
+    fn chmin(&mut self, k: usize, l: usize, r: usize, a: usize, b: usize, x: i64) {
+        if b <= l || r <= a || self.nodes[k].max <= x {
+            return;
+        }
+        if a <= l && r <= b && (r - l == 1 || self.nodes[k].max2 < x) {
+            self.nodes[k].chmin(x);
+            return;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.chmin(k << 1, l, m, a, b, x);
+        self.chmin((k << 1) + 1, m, r, a, b, x);
+        self.pull(k);
+    }
+
+    fn chmax(&mut self, k: usize, l: usize, r: usize, a: usize, b: usize, x: i64) {
+        if b <= l || r <= a || self.nodes[k].min >= x {
+            return;
+        }
+        if a <= l && r <= b && (r - l == 1 || self.nodes[k].min2 > x) {
+            self.nodes[k].chmax(x);
+            return;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.chmax(k << 1, l, m, a, b, x);
+        self.chmax((k << 1) + 1, m, r, a, b, x);
+        self.pull(k);
+    }
+
+    fn add(&mut self, k: usize, l: usize, r: usize, a: usize, b: usize, x: i64) {
+        if b <= l || r <= a {
+            return;
+        }
+        if a <= l && r <= b {
+            self.nodes[k].add(x);
+            return;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.add(k << 1, l, m, a, b, x);
+        self.add((k << 1) + 1, m, r, a, b, x);
+        self.pull(k);
+    }
+
+    fn sum(&mut self, k: usize, l: usize, r: usize, a: usize, b: usize) -> i64 {
+        if b <= l || r <= a {
+            return 0;
+        }
+        if a <= l && r <= b {
+            return self.nodes[k].sum;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.sum(k << 1, l, m, a, b) + self.sum((k << 1) + 1, m, r, a, b)
+    }
+}
@@ -0,0 +1,108 @@
+use algebrae::algebra::Monoid;
+
+use crate::segment_tree::SegmentTree;
+
+/// A segment tree indexed by a fixed set of sparse coordinates, which are compressed to
+/// consecutive indices on construction.
+///
+/// # Complexity
+/// Space: O(n)
+pub struct CompressedSegmentTree<K: Ord, S: Monoid> {
+    keys: Box<[K]>,
+    tree: SegmentTree<S>,
+}
+
+impl<K: Ord, S: Monoid> CompressedSegmentTree<K, S> {
+    /// Creates a new compressed segment tree over the distinct values of `coords`, where all
+    /// initialized to `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn new(mut coords: Vec<K>) -> Self {
+        coords.sort_unstable();
+        coords.dedup();
+        debug_assert!(!coords.is_empty(), "coords must not be empty");
+        let n = coords.len();
+        Self {
+            keys: coords.into_boxed_slice(),
+            tree: SegmentTree::new(n),
+        }
+    }
+
+    /// Sets the value at coordinate `k` to `x`. `k` must be one of the coordinates.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn set(&mut self, k: &K, x: S) {
+        let i = self.index(k);
+        self.tree.set(i, x);
+    }
+
+    /// Sets the value at coordinate `k` to `op(a[k], x)`. `k` must be one of the coordinates.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn operate(&mut self, k: &K, x: S) {
+        let i = self.index(k);
+        self.tree.operate(i, x);
+    }
+
+    /// Returns the value at coordinate `k`. `k` must be one of the coordinates.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn get(&self, k: &K) -> S {
+        self.tree.get(self.index(k))
+    }
+
+    /// Returns the fold of the values at all coordinates in `range`, in increasing order of
+    /// coordinate. The bounds need not be coordinates themselves. When no coordinate lies in
+    /// range, return `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn range_fold(&self, range: impl std::ops::RangeBounds<K>) -> S {
+        let l = match range.start_bound() {
+            std::ops::Bound::Unbounded => 0,
+            std::ops::Bound::Included(x) => self.keys.partition_point(|k| k < x),
+            std::ops::Bound::Excluded(x) => self.keys.partition_point(|k| k <= x),
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Unbounded => self.keys.len(),
+            std::ops::Bound::Included(x) => self.keys.partition_point(|k| k <= x),
+            std::ops::Bound::Excluded(x) => self.keys.partition_point(|k| k < x),
+        };
+        if l >= r {
+            return S::id();
+        }
+        self.tree.range_fold(l..r)
+    }
+
+    /// Returns the sorted distinct coordinates.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Returns the number of distinct coordinates.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[allow(clippy::len_without_is_empty)]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn index(&self, k: &K) -> usize {
+        let i = self.keys.partition_point(|x| x < k);
+        debug_assert!(
+            i < self.keys.len() && self.keys[i] == *k,
+            "coordinate was not registered"
+        );
+        i
+    }
+}
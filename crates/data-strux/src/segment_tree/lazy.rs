@@ -1,9 +1,12 @@
 use algebrae::algebra::{Action, Monoid};
 
+use crate::segment_tree::cover;
+
 /// A lazy segment tree structure.
 ///
 /// # Complexity
 /// Space: O(n)
+#[derive(Clone)]
 pub struct LazySegmentTree<S: Monoid, F: Monoid + Action<S>> {
     data: Box<[S]>,
     lazy: Box<[F]>,
@@ -238,6 +241,9 @@ impl<S: Monoid, F: Monoid + Action<S>> LazySegmentTree<S, F> {
             r - self.len(),
             self.len(),
         );
+        if l == r {
+            return S::id();
+        }
         l >>= l.trailing_zeros();
         r >>= r.trailing_zeros();
 
@@ -284,12 +290,110 @@ impl<S: Monoid, F: Monoid + Action<S>> LazySegmentTree<S, F> {
         res
     }
 
-    /// Returns `op(a[0], ..., a[n - 1])`.
+    /// Returns the largest `r` such that `pred(op(a[l], ..., a[r - 1]))` holds, assuming `pred`
+    /// is monotone (once false, it stays false as the range grows). `pred(&S::id())` must be
+    /// true. When `l == len()` returns `len()`, and when `pred` already fails on `a[l]` returns
+    /// `l`. Pending actions on the visited path are pushed down, hence `&mut self`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn max_right<P: Fn(&S) -> bool>(&mut self, l: usize, pred: P) -> usize {
+        let n = self.len();
+        debug_assert!(l <= n, "index out of bounds: l={}, len={}", l, n);
+        debug_assert!(pred(&S::id()), "pred(id) must be true");
+        if l == n {
+            return n;
+        }
+        let (nodes, len) = cover(n, l, n);
+        self.propagate((l + n) >> (l + n).trailing_zeros());
+        self.propagate(((n << 1) >> (n << 1).trailing_zeros()) - 1);
+        let mut acc = S::id();
+        for &k in nodes[..len].iter() {
+            let t = S::op(&acc, &self.data[k]);
+            if pred(&t) {
+                acc = t;
+                continue;
+            }
+            let mut k = k;
+            while k < n {
+                self.push(k);
+                k <<= 1;
+                let t = S::op(&acc, &self.data[k]);
+                if pred(&t) {
+                    acc = t;
+                    k += 1;
+                }
+            }
+            return k - n;
+        }
+        n
+    }
+
+    /// Returns the smallest `l` such that `pred(op(a[l], ..., a[r - 1]))` holds, assuming `pred`
+    /// is monotone (once false, it stays false as the range grows). `pred(&S::id())` must be
+    /// true. When `r == 0` returns `0`, and when `pred` already fails on `a[r - 1]` returns `r`.
+    /// Pending actions on the visited path are pushed down, hence `&mut self`.
     ///
     /// # Complexity
-    /// O(n log n)
+    /// Time: O(log n)
+    pub fn min_left<P: Fn(&S) -> bool>(&mut self, r: usize, pred: P) -> usize {
+        let n = self.len();
+        debug_assert!(r <= n, "index out of bounds: r={}, len={}", r, n);
+        debug_assert!(pred(&S::id()), "pred(id) must be true");
+        if r == 0 {
+            return 0;
+        }
+        let (nodes, len) = cover(n, 0, r);
+        self.propagate(n >> n.trailing_zeros());
+        self.propagate(((r + n) >> (r + n).trailing_zeros()) - 1);
+        let mut acc = S::id();
+        for &k in nodes[..len].iter().rev() {
+            let t = S::op(&self.data[k], &acc);
+            if pred(&t) {
+                acc = t;
+                continue;
+            }
+            let mut k = k;
+            while k < n {
+                self.push(k);
+                k = (k << 1) + 1;
+                let t = S::op(&self.data[k], &acc);
+                if pred(&t) {
+                    acc = t;
+                    k -= 1;
+                }
+            }
+            return k + 1 - n;
+        }
+        0
+    }
+
+    /// Returns `op(a[0], ..., a[n - 1])`. The root already has its own pending action applied,
+    /// but it combines the leaves in order only when `n` is a power of two. Otherwise the leaves
+    /// wrap around the implicit tree, so this falls back to `range_fold` to keep the order for
+    /// non-commutative monoids.
+    ///
+    /// # Complexity
+    /// Time: O(1) if `n` is a power of two, otherwise O(log n)
     pub fn all_fold(&self) -> S {
-        self.range_fold(..)
+        if self.len().is_power_of_two() {
+            unsafe { self.data.get_unchecked(1).clone() }
+        } else {
+            self.range_fold(..)
+        }
+    }
+
+    /// Returns all elements as a vec after pushing every pending action down to the leaves. This
+    /// is handy for comparing two trees element-wise.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn to_vec(&mut self) -> Vec<S> {
+        let n = self.len();
+        for k in 1..n {
+            self.push(k);
+        }
+        self.data[n..].to_vec()
     }
 
     /// Returns the number of elements.
@@ -312,19 +416,26 @@ impl<S: Monoid, F: Monoid + Action<S>> LazySegmentTree<S, F> {
 
     #[inline(always)]
     fn propagate(&mut self, i: usize) {
+        for t in (1..(usize::BITS - i.leading_zeros()) as usize).rev() {
+            self.push(i >> t);
+        }
+    }
+
+    /// Pushes the pending action of the internal node `k` down to its children.
+    #[inline(always)]
+    fn push(&mut self, k: usize) {
         let n = self.len();
         let data = self.data.as_mut_ptr();
         let lazy = self.lazy.as_mut_ptr();
         unsafe {
-            for t in (1..(usize::BITS - i.leading_zeros()) as usize).rev() {
-                let k = i >> t;
-                let f = std::ptr::replace(lazy.add(k), F::id());
-                *data.add(k << 1) = f.act(&*data.add(k << 1));
-                *data.add((k << 1) + 1) = f.act(&*data.add((k << 1) + 1));
-                if k << 1 < n {
-                    *lazy.add(k << 1) = F::op(&f, &*lazy.add(k << 1));
-                    *lazy.add((k << 1) + 1) = F::op(&f, &*lazy.add((k << 1) + 1));
-                }
+            let f = std::ptr::replace(lazy.add(k), F::id());
+            *data.add(k << 1) = f.act(&*data.add(k << 1));
+            *data.add((k << 1) + 1) = f.act(&*data.add((k << 1) + 1));
+            if k << 1 < n {
+                *lazy.add(k << 1) = F::op(&f, &*lazy.add(k << 1));
+            }
+            if (k << 1) + 1 < n {
+                *lazy.add((k << 1) + 1) = F::op(&f, &*lazy.add((k << 1) + 1));
             }
         }
     }
@@ -1,7 +1,48 @@
 mod base;
+mod beats;
+mod compressed;
 mod dual;
 mod lazy;
+mod persistent;
+mod two_dim;
 
 pub use base::SegmentTree;
+pub use beats::SegmentTreeBeats;
+pub use compressed::CompressedSegmentTree;
 pub use dual::DualSegmentTree;
 pub use lazy::LazySegmentTree;
+pub use persistent::PersistentSegmentTree;
+pub use two_dim::SegmentTree2D;
+
+/// Returns the nodes of a bottom-up segment tree with `n` leaves covering `l..r`, from left to
+/// right, as a buffer and its length. These are the nodes `range_fold` visits.
+fn cover(n: usize, l: usize, r: usize) -> ([usize; 2 * usize::BITS as usize], usize) {
+    let mut nodes = [0; 2 * usize::BITS as usize];
+    let mut right = [0; usize::BITS as usize];
+    let (mut len, mut right_len) = (0, 0);
+    let mut l = l + n;
+    let mut r = r + n;
+    l >>= l.trailing_zeros();
+    r >>= r.trailing_zeros();
+    loop {
+        if l >= r {
+            nodes[len] = l;
+            len += 1;
+            l += 1;
+            l >>= l.trailing_zeros();
+        } else {
+            r -= 1;
+            right[right_len] = r;
+            right_len += 1;
+            r >>= r.trailing_zeros();
+        }
+        if l == r {
+            break;
+        }
+    }
+    for &k in right[..right_len].iter().rev() {
+        nodes[len] = k;
+        len += 1;
+    }
+    (nodes, len)
+}
@@ -0,0 +1,214 @@
+use algebrae::algebra::Monoid;
+
+/// A fully persistent segment tree with path copying. Every update creates a new version and
+/// leaves all older versions intact. Version 0 is the initial array.
+///
+/// # Complexity
+/// Space: O(n + q log n), where q is the number of updates.
+pub struct PersistentSegmentTree<S: Monoid> {
+    n: usize,
+    // Branches hold their children, and leaves hold `[!0, !0]`.
+    nodes: Vec<[usize; 2]>,
+    data: Vec<S>,
+    roots: Vec<usize>,
+}
+
+impl<S: Monoid> PersistentSegmentTree<S> {
+    /// Creates a new persistent segment tree filled with `S::id()` as version 0.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn new(n: usize) -> Self {
+        Self::from_vec(vec![S::id(); n])
+    }
+
+    /// Creates a new persistent segment tree from a vec as version 0.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn from_vec(v: Vec<S>) -> Self {
+        let n = v.len();
+        debug_assert!(n > 0, "n must not be zero");
+        let mut res = Self {
+            n,
+            nodes: Vec::with_capacity(n << 1),
+            data: Vec::with_capacity(n << 1),
+            roots: Vec::new(),
+        };
+        let root = res.build(0, n, &v);
+        res.roots.push(root);
+        res
+    }
+
+    /// Creates a new persistent segment tree from a slice as version 0.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn from_slice(v: &[S]) -> Self {
+        Self::from_vec(v.to_vec())
+    }
+
+    fn build(&mut self, l: usize, r: usize, v: &[S]) -> usize {
+        if r - l == 1 {
+            self.nodes.push([!0, !0]);
+            self.data.push(v[l].clone());
+        } else {
+            let m = (l + r) >> 1;
+            let left = self.build(l, m, v);
+            let right = self.build(m, r, v);
+            self.nodes.push([left, right]);
+            self.data.push(self.data[left].op(&self.data[right]));
+        }
+        self.nodes.len() - 1
+    }
+
+    /// Creates a new version equal to `version` except that index `i` is `x`, and returns it.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn set(&mut self, version: usize, i: usize, x: S) -> usize {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        debug_assert!(i < self.n, "index out of bounds: i={}, len={}", i, self.n);
+        let mut path = Vec::new();
+        let mut node = self.roots[version];
+        let (mut l, mut r) = (0, self.n);
+        while r - l > 1 {
+            let m = (l + r) >> 1;
+            let side = (i >= m) as usize;
+            path.push((node, side));
+            node = self.nodes[node][side];
+            if side == 0 {
+                r = m;
+            } else {
+                l = m;
+            }
+        }
+        self.nodes.push([!0, !0]);
+        self.data.push(x);
+        let mut child = self.nodes.len() - 1;
+        for (node, side) in path.into_iter().rev() {
+            let mut copy = self.nodes[node];
+            copy[side] = child;
+            self.nodes.push(copy);
+            self.data.push(self.data[copy[0]].op(&self.data[copy[1]]));
+            child = self.nodes.len() - 1;
+        }
+        self.roots.push(child);
+        self.roots.len() - 1
+    }
+
+    /// Returns the value at index `i` in `version`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn get(&self, version: usize, i: usize) -> &S {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        debug_assert!(i < self.n, "index out of bounds: i={}, len={}", i, self.n);
+        let mut node = self.roots[version];
+        let (mut l, mut r) = (0, self.n);
+        while r - l > 1 {
+            let m = (l + r) >> 1;
+            if i < m {
+                node = self.nodes[node][0];
+                r = m;
+            } else {
+                node = self.nodes[node][1];
+                l = m;
+            }
+        }
+        &self.data[node]
+    }
+
+    /// Returns `op(a[l], ..., a[r - 1])` in `version`. When range is empty, return `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn range_fold(&self, version: usize, range: impl std::ops::RangeBounds<usize>) -> S {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        let l = match range.start_bound() {
+            std::ops::Bound::Unbounded => 0,
+            std::ops::Bound::Included(&x) => x,
+            std::ops::Bound::Excluded(&x) => x + 1,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Unbounded => self.n,
+            std::ops::Bound::Included(&x) => x + 1,
+            std::ops::Bound::Excluded(&x) => x,
+        };
+        debug_assert!(
+            l <= r,
+            "left bound must be less than or equal to right bound: l={}, r={}",
+            l,
+            r,
+        );
+        debug_assert!(r <= self.n, "index out of bounds: r={}, len={}", r, self.n);
+        if l == r {
+            return S::id();
+        }
+        self.fold(self.roots[version], 0, self.n, l, r)
+    }
+
+    fn fold(&self, node: usize, l: usize, r: usize, a: usize, b: usize) -> S {
+        if a <= l && r <= b {
+            return self.data[node].clone();
+        }
+        let m = (l + r) >> 1;
+        let [left, right] = self.nodes[node];
+        if b <= m {
+            self.fold(left, l, m, a, b)
+        } else if m <= a {
+            self.fold(right, m, r, a, b)
+        } else {
+            self.fold(left, l, m, a, b)
+                .op(&self.fold(right, m, r, a, b))
+        }
+    }
+
+    /// Returns `op(a[0], ..., a[n - 1])` in `version`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn all_fold(&self, version: usize) -> S {
+        debug_assert!(
+            version < self.num_versions(),
+            "version out of bounds: version={}, versions={}",
+            version,
+            self.num_versions(),
+        );
+        self.data[self.roots[version]].clone()
+    }
+
+    /// Returns the number of versions created so far, including version 0.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn num_versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.n
+    }
+}
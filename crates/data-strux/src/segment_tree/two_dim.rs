@@ -0,0 +1,207 @@
+use algebrae::algebra::Monoid;
+
+/// A two-dimensional segment tree structure over an `h` x `w` grid. Folds combine cells in no
+/// particular order, so the monoid should be commutative.
+///
+/// # Complexity
+/// Space: O(hw)
+pub struct SegmentTree2D<S: Monoid> {
+    h: usize,
+    w: usize,
+    data: Box<[S]>,
+}
+
+impl<S: Monoid> SegmentTree2D<S> {
+    /// Creates a new 2D segment tree with `h` x `w` elements, where all initialized to `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(hw)
+    pub fn new(h: usize, w: usize) -> Self {
+        debug_assert!(h > 0 && w > 0, "h and w must not be zero: h={}, w={}", h, w);
+        Self {
+            h,
+            w,
+            data: vec![S::id(); (h << 1) * (w << 1)].into_boxed_slice(),
+        }
+    }
+
+    /// Creates a 2D segment tree from a grid given as rows of equal length.
+    ///
+    /// # Complexity
+    /// Time: O(hw)
+    pub fn from_vec(v: Vec<Vec<S>>) -> Self {
+        let h = v.len();
+        debug_assert!(h > 0, "h must not be zero");
+        let w = v[0].len();
+        let mut res = Self::new(h, w);
+        let stride = w << 1;
+        unsafe {
+            let d = res.data.as_mut_ptr();
+            for (i, row) in v.into_iter().enumerate() {
+                debug_assert_eq!(row.len(), w, "row length mismatch: i={}", i);
+                let base = d.add((h + i) * stride);
+                for (j, x) in row.into_iter().enumerate() {
+                    *base.add(w + j) = x;
+                }
+                for j in (1..w).rev() {
+                    *base.add(j) = S::op(&*base.add(j << 1), &*base.add((j << 1) + 1));
+                }
+            }
+            for i in (1..h).rev() {
+                let (lo, hi) = (d.add((i << 1) * stride), d.add(((i << 1) + 1) * stride));
+                let base = d.add(i * stride);
+                for j in 1..stride {
+                    *base.add(j) = S::op(&*lo.add(j), &*hi.add(j));
+                }
+            }
+        }
+        res
+    }
+
+    /// Sets the value at `(r, c)` to `x`.
+    ///
+    /// # Complexity
+    /// Time: O(log h log w)
+    pub fn set(&mut self, r: usize, c: usize, x: S) {
+        debug_assert!(
+            r < self.h && c < self.w,
+            "index out of bounds: r={}, c={}, h={}, w={}",
+            r,
+            c,
+            self.h,
+            self.w,
+        );
+        let stride = self.w << 1;
+        let mut i = r + self.h;
+        unsafe {
+            let d = self.data.as_mut_ptr();
+            let base = d.add(i * stride);
+            let mut j = c + self.w;
+            *base.add(j) = x;
+            while j > 1 {
+                j >>= 1;
+                *base.add(j) = S::op(&*base.add(j << 1), &*base.add((j << 1) + 1));
+            }
+            while i > 1 {
+                i >>= 1;
+                let (lo, hi) = (d.add((i << 1) * stride), d.add(((i << 1) + 1) * stride));
+                let base = d.add(i * stride);
+                let mut j = c + self.w;
+                while j > 0 {
+                    *base.add(j) = S::op(&*lo.add(j), &*hi.add(j));
+                    j >>= 1;
+                }
+            }
+        }
+    }
+
+    /// Returns the value at `(r, c)`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn get(&self, r: usize, c: usize) -> S {
+        debug_assert!(
+            r < self.h && c < self.w,
+            "index out of bounds: r={}, c={}, h={}, w={}",
+            r,
+            c,
+            self.h,
+            self.w,
+        );
+        unsafe {
+            self.data
+                .get_unchecked((r + self.h) * (self.w << 1) + c + self.w)
+                .clone()
+        }
+    }
+
+    /// Returns the fold of all `a[r][c]` with `r` in `rows` and `c` in `cols`. When either range
+    /// is empty, return `S::id()`.
+    ///
+    /// # Complexity
+    /// Time: O(log h log w)
+    pub fn fold(
+        &self,
+        rows: impl std::ops::RangeBounds<usize>,
+        cols: impl std::ops::RangeBounds<usize>,
+    ) -> S {
+        let (mut t, mut b) = bounds(rows, self.h);
+        let (l, r) = bounds(cols, self.w);
+        let mut res = S::id();
+        while t < b {
+            if t & 1 == 1 {
+                res = S::op(&res, &self.row_fold(t, l, r));
+                t += 1;
+            }
+            if b & 1 == 1 {
+                b -= 1;
+                res = S::op(&res, &self.row_fold(b, l, r));
+            }
+            t >>= 1;
+            b >>= 1;
+        }
+        res
+    }
+
+    /// Returns the fold over the column range `l..r` of the node row `i`, where `l` and `r` are
+    /// already offset by `w`.
+    fn row_fold(&self, i: usize, mut l: usize, mut r: usize) -> S {
+        let mut res = S::id();
+        unsafe {
+            let base = self.data.as_ptr().add(i * (self.w << 1));
+            while l < r {
+                if l & 1 == 1 {
+                    res = S::op(&res, &*base.add(l));
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    res = S::op(&res, &*base.add(r));
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+        }
+        res
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    /// Returns the number of columns.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        self.w
+    }
+}
+
+/// Resolves `range` against length `n` and offsets it by `n` to leaf positions.
+fn bounds(range: impl std::ops::RangeBounds<usize>, n: usize) -> (usize, usize) {
+    let l = match range.start_bound() {
+        std::ops::Bound::Unbounded => 0,
+        std::ops::Bound::Included(&x) => x,
+        std::ops::Bound::Excluded(&x) => x + 1,
+    };
+    let r = match range.end_bound() {
+        std::ops::Bound::Unbounded => n,
+        std::ops::Bound::Included(&x) => x + 1,
+        std::ops::Bound::Excluded(&x) => x,
+    };
+    debug_assert!(
+        l <= r,
+        "left bound must be less than or equal to right bound: l={}, r={}",
+        l,
+        r,
+    );
+    debug_assert!(r <= n, "index out of bounds: r={}, len={}", r, n);
+    (l + n, r + n)
+}
@@ -0,0 +1,40 @@
+use crate::point2d::Point2D;
+
+/// Returns the intersection of two convex polygons as a counter-clockwise vertex list, computed by
+/// clipping `a` against the half-plane left of every edge of `b` (Sutherland-Hodgman). Both
+/// polygons must be given in counter-clockwise order. If the polygons are disjoint returns an
+/// empty vector. When they only touch, the result may be a degenerate polygon of zero area.
+///
+/// # Complexity
+/// Time: O(nm)
+pub fn convex_intersection(a: &[Point2D<i64>], b: &[Point2D<i64>]) -> Vec<Point2D<f64>> {
+    let to_f64 = |p: Point2D<i64>| Point2D::new(p.x() as f64, p.y() as f64);
+    let mut res: Vec<Point2D<f64>> = a.iter().map(|&p| to_f64(p)).collect();
+    let m = b.len();
+    for i in 0..m {
+        if res.is_empty() {
+            break;
+        }
+        let (s, t) = (to_f64(b[i]), to_f64(b[(i + 1) % m]));
+        let e = s.to(t);
+        let side = |p: Point2D<f64>| e.outer(s.to(p));
+        let k = res.len();
+        let mut clipped = Vec::with_capacity(k + 1);
+        for j in 0..k {
+            let (p, q) = (res[j], res[(j + 1) % k]);
+            let (sp, sq) = (side(p), side(q));
+            if sp >= 0.0 {
+                clipped.push(p);
+            }
+            if (sp < 0.0 && sq > 0.0) || (sp > 0.0 && sq < 0.0) {
+                let r = sp / (sp - sq);
+                clipped.push(Point2D::new(
+                    p.x() + (q.x() - p.x()) * r,
+                    p.y() + (q.y() - p.y()) * r,
+                ));
+            }
+        }
+        res = clipped;
+    }
+    res
+}
@@ -0,0 +1,45 @@
+use crate::point2d::{Point2D, convex_hull};
+
+/// Computes convex layers (onion peeling) of a set of points. Each layer is the boundary of the
+/// convex hull of the remaining points in counter-clockwise order, including points lying on a
+/// hull edge. Duplicate points are merged into one, and a degenerate layer where the remaining
+/// points are collinear is returned in sorted order.
+///
+/// # Complexity
+/// Time: O(n^2)
+pub fn convex_layers(points: &mut [Point2D<i64>]) -> Vec<Vec<Point2D<i64>>> {
+    points.sort_unstable_by_key(|point| (point.x(), point.y()));
+    let mut rest = points.to_vec();
+    rest.dedup();
+
+    let mut layers = Vec::new();
+    while !rest.is_empty() {
+        let hull = convex_hull(&mut rest);
+        if hull.len() <= 2 {
+            layers.push(std::mem::take(&mut rest));
+            break;
+        }
+
+        let h = hull.len();
+        let mut layer = Vec::new();
+        let mut inner = Vec::with_capacity(rest.len());
+        for &point in rest.iter() {
+            let mut on_edge = None;
+            for i in 0..h {
+                let (a, b) = (hull[i], hull[(i + 1) % h]);
+                if point != b && a.to(b).outer(a.to(point)) == 0 {
+                    on_edge = Some((i, a.to(point).inner(a.to(point))));
+                    break;
+                }
+            }
+            match on_edge {
+                Some(key) => layer.push((key, point)),
+                None => inner.push(point),
+            }
+        }
+        layer.sort_unstable_by_key(|&(key, _)| key);
+        layers.push(layer.into_iter().map(|(_, point)| point).collect());
+        rest = inner;
+    }
+    layers
+}
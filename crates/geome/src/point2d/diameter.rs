@@ -0,0 +1,52 @@
+use crate::point2d::{Point2D, convex_hull};
+
+/// Returns the squared diameter of a set of points, the maximum squared distance over all pairs,
+/// by rotating calipers on its convex hull. If there are fewer than two distinct points returns 0.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn diameter(points: &mut [Point2D<i64>]) -> i64 {
+    let hull = convex_hull(points);
+    let h = hull.len();
+    let dist = |a: Point2D<i64>, b: Point2D<i64>| a.to(b).inner(a.to(b));
+    if h <= 2 {
+        return if h == 2 { dist(hull[0], hull[1]) } else { 0 };
+    }
+    let mut res = 0;
+    let mut j = 1;
+    for i in 0..h {
+        let (a, b) = (hull[i], hull[(i + 1) % h]);
+        let e = a.to(b);
+        while e.outer(a.to(hull[(j + 1) % h])) > e.outer(a.to(hull[j])) {
+            j = (j + 1) % h;
+        }
+        res = res.max(dist(a, hull[j])).max(dist(b, hull[j]));
+    }
+    res
+}
+
+/// Returns the width of a set of points, the minimum distance between two parallel lines
+/// enclosing all points, by rotating calipers on its convex hull. If all points are collinear
+/// returns 0.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn width(points: &mut [Point2D<i64>]) -> f64 {
+    let hull = convex_hull(points);
+    let h = hull.len();
+    if h <= 2 {
+        return 0.0;
+    }
+    let mut res = f64::INFINITY;
+    let mut j = 1;
+    for i in 0..h {
+        let (a, b) = (hull[i], hull[(i + 1) % h]);
+        let e = a.to(b);
+        while e.outer(a.to(hull[(j + 1) % h])) > e.outer(a.to(hull[j])) {
+            j = (j + 1) % h;
+        }
+        let height = e.outer(a.to(hull[j])) as f64 / (e.inner(e) as f64).sqrt();
+        res = res.min(height);
+    }
+    res
+}
@@ -0,0 +1,97 @@
+use crate::point2d::Point2D;
+
+/// A static 2-d tree over a set of points for nearest neighbor queries. Squared distances are
+/// computed in `i64`, so coordinates should satisfy |x|, |y| <= 10^9.
+///
+/// # Complexity
+/// Space: O(n)
+pub struct KdTree2D {
+    // Each subrange stores its median at the middle, split by x at even depth and by y at odd.
+    nodes: Box<[(Point2D<i64>, usize)]>,
+}
+
+impl KdTree2D {
+    /// Creates a new k-d tree from a slice. Query results refer to indices of this slice.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn from_slice(points: &[Point2D<i64>]) -> Self {
+        let mut nodes: Vec<(Point2D<i64>, usize)> =
+            points.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+        Self::build(&mut nodes, 0);
+        Self {
+            nodes: nodes.into_boxed_slice(),
+        }
+    }
+
+    fn build(nodes: &mut [(Point2D<i64>, usize)], depth: usize) {
+        if nodes.len() <= 1 {
+            return;
+        }
+        let m = nodes.len() >> 1;
+        if depth & 1 == 0 {
+            nodes.select_nth_unstable_by_key(m, |(p, _)| p.x());
+        } else {
+            nodes.select_nth_unstable_by_key(m, |(p, _)| p.y());
+        }
+        let (left, right) = nodes.split_at_mut(m);
+        Self::build(left, depth + 1);
+        Self::build(&mut right[1..], depth + 1);
+    }
+
+    /// Returns `(index, squared distance)` of the stored point nearest to `p`. Among equally near
+    /// points the smallest index is returned.
+    ///
+    /// # Complexity
+    /// Time: O(log n) on average
+    pub fn nearest(&self, p: Point2D<i64>) -> (usize, i64) {
+        debug_assert!(!self.nodes.is_empty(), "k-d tree must not be empty");
+        let mut best = (i64::MAX, !0);
+        self.search(0, self.nodes.len(), 0, p, &mut best);
+        (best.1, best.0)
+    }
+
+    fn search(&self, l: usize, r: usize, depth: usize, p: Point2D<i64>, best: &mut (i64, usize)) {
+        if l >= r {
+            return;
+        }
+        let m = l + ((r - l) >> 1);
+        let (q, i) = unsafe { *self.nodes.get_unchecked(m) };
+        let d = q.to(p).inner(q.to(p));
+        if (d, i) < *best {
+            *best = (d, i);
+        }
+        let diff = if depth & 1 == 0 {
+            p.x() - q.x()
+        } else {
+            p.y() - q.y()
+        };
+        let (near, far) = if diff < 0 {
+            ((l, m), (m + 1, r))
+        } else {
+            ((m + 1, r), (l, m))
+        };
+        self.search(near.0, near.1, depth + 1, p, best);
+        if diff * diff <= best.0 {
+            self.search(far.0, far.1, depth + 1, p, best);
+        }
+    }
+
+    /// Returns the number of points.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether the k-d tree is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
@@ -0,0 +1,130 @@
+use crate::point2d::{Point2D, convex_hull};
+
+/// Returns the area of a minimum-area rectangle enclosing a set of points as an exact fraction
+/// `(num, den)` with `den > 0`, together with its four corners in counter-clockwise order. For the
+/// rectangle flush with the hull edge `e`, `num` is the product of its extents along `e` and
+/// across it, each scaled by `|e|`, and `den` is `|e|^2`, left unreduced. An optimal rectangle has a side flush with an edge of the
+/// convex hull, so rotating calipers track the farthest point from each hull edge and the extreme
+/// points along it, and candidates are compared exactly. If all points are collinear the rectangle
+/// degenerates to the enclosing segment and the area is `(0, 1)`. Coordinates must be less than
+/// 2^30 in absolute value.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn min_area_rectangle(points: &mut [Point2D<i64>]) -> ((i128, i128), [Point2D<f64>; 4]) {
+    debug_assert!(!points.is_empty(), "points must not be empty");
+    let hull = convex_hull(points);
+    let h = hull.len();
+    let f = |p: Point2D<i64>| Point2D::new(p.x() as f64, p.y() as f64);
+    if h <= 2 {
+        let (a, b) = (f(hull[0]), f(hull[h - 1]));
+        return ((0, 1), [a, b, b, a]);
+    }
+    let dot = |i: usize, p: Point2D<i64>| {
+        let (a, b) = (hull[i], hull[(i + 1) % h]);
+        a.to(b).inner(a.to(p)) as i128
+    };
+    let cross = |i: usize, p: Point2D<i64>| {
+        let (a, b) = (hull[i], hull[(i + 1) % h]);
+        a.to(b).outer(a.to(p)) as i128
+    };
+
+    let mut best = ((0, 0), 0, 0, 0, 0);
+    let (mut top, mut right, mut left) = (1, 1, 0);
+    for i in 0..h {
+        while cross(i, hull[(top + 1) % h]) > cross(i, hull[top]) {
+            top = (top + 1) % h;
+        }
+        while dot(i, hull[(right + 1) % h]) > dot(i, hull[right]) {
+            right = (right + 1) % h;
+        }
+        if i == 0 {
+            left = top;
+        }
+        while dot(i, hull[(left + 1) % h]) < dot(i, hull[left]) {
+            left = (left + 1) % h;
+        }
+        let len2 = hull[i]
+            .to(hull[(i + 1) % h])
+            .inner(hull[i].to(hull[(i + 1) % h])) as i128;
+        let area = (
+            (dot(i, hull[right]) - dot(i, hull[left])) * cross(i, hull[top]),
+            len2,
+        );
+        if i == 0 || less(area, best.0) {
+            best = (area, i, top, right, left);
+        }
+    }
+
+    let (area, i, top, right, left) = best;
+    let (a, b) = (hull[i], hull[(i + 1) % h]);
+    let len = (a.to(b).inner(a.to(b)) as f64).sqrt();
+    let (ux, uy) = ((b.x() - a.x()) as f64 / len, (b.y() - a.y()) as f64 / len);
+    let s_min = dot(i, hull[left]) as f64 / len;
+    let s_max = dot(i, hull[right]) as f64 / len;
+    let t = cross(i, hull[top]) as f64 / len;
+    let corner = |s: f64, t: f64| {
+        Point2D::new(
+            a.x() as f64 + s * ux - t * uy,
+            a.y() as f64 + s * uy + t * ux,
+        )
+    };
+    (
+        area,
+        [
+            corner(s_min, 0.0),
+            corner(s_max, 0.0),
+            corner(s_max, t),
+            corner(s_min, t),
+        ],
+    )
+}
+
+/// Returns whether `a.0 / a.1 < b.0 / b.1` for non-negative numerators and positive denominators,
+/// comparing the 256-bit cross products.
+fn less(a: (i128, i128), b: (i128, i128)) -> bool {
+    let mul = |x: u128, y: u128| {
+        let (x1, x0) = (x >> 64, x & u64::MAX as u128);
+        let (y1, y0) = (y >> 64, y & u64::MAX as u128);
+        let lo = x0 * y0;
+        let mid1 = x1 * y0;
+        let mid2 = x0 * y1;
+        let (mid, carry) = mid1.overflowing_add(mid2);
+        let (lo, c) = lo.overflowing_add(mid << 64);
+        let hi = x1 * y1 + (mid >> 64) + ((carry as u128) << 64) + c as u128;
+        (hi, lo)
+    };
+    mul(a.0 as u128, b.1 as u128) < mul(b.0 as u128, a.1 as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axis_aligned_square() {
+        let mut points = [(0, 0), (2, 0), (2, 2), (0, 2), (1, 1)].map(|(x, y)| Point2D::new(x, y));
+        let ((num, den), corners) = min_area_rectangle(&mut points);
+        assert_eq!(num, 4 * den);
+        for p in corners {
+            assert!([0.0, 2.0].contains(&p.x()) && [0.0, 2.0].contains(&p.y()));
+        }
+    }
+
+    #[test]
+    fn rotated_rectangle() {
+        // Sides (1, 1) and (-3, 3), so the area is sqrt(2) * sqrt(18) = 6.
+        let mut points =
+            [(0, 0), (1, 1), (-2, 4), (-3, 3), (-1, 2)].map(|(x, y)| Point2D::new(x, y));
+        let ((num, den), corners) = min_area_rectangle(&mut points);
+        assert_eq!(num, 6 * den);
+        let expected = [(0.0, 0.0), (1.0, 1.0), (-2.0, 4.0), (-3.0, 3.0)];
+        for p in corners {
+            assert!(
+                expected
+                    .iter()
+                    .any(|&(x, y)| (p.x() - x).abs() < 1e-9 && (p.y() - y).abs() < 1e-9)
+            );
+        }
+    }
+}
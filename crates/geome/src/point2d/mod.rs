@@ -1,6 +1,24 @@
 mod base;
 mod convex_hull;
+mod convex_intersection;
+mod convex_layers;
+mod diameter;
+mod kd_tree;
+mod min_area_rectangle;
 mod p2v;
+mod radial_sort;
+mod segment_intersection;
+mod triangulate;
+mod winding_number;
 
 pub use base::{Point2D, p2};
 pub use convex_hull::convex_hull;
+pub use convex_intersection::convex_intersection;
+pub use convex_layers::convex_layers;
+pub use diameter::{diameter, width};
+pub use kd_tree::KdTree2D;
+pub use min_area_rectangle::min_area_rectangle;
+pub use radial_sort::radial_sort;
+pub use segment_intersection::count_intersections;
+pub use triangulate::triangulate;
+pub use winding_number::winding_number;
@@ -0,0 +1,18 @@
+use crate::point2d::Point2D;
+
+/// Returns the indices of `points` sorted by the argument of `pivot.to(point)` in (-pi, pi],
+/// that is counter-clockwise starting just after the negative x-axis. Points in the same
+/// direction are ordered by distance from `pivot`, nearest first, and equal points by index.
+/// Points equal to `pivot` have no direction and are left out.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn radial_sort(pivot: Point2D<i64>, points: &[Point2D<i64>]) -> Vec<usize> {
+    let mut res: Vec<usize> = (0..points.len()).filter(|&i| points[i] != pivot).collect();
+    res.sort_by(|&i, &j| {
+        let (a, b) = (pivot.to(points[i]), pivot.to(points[j]));
+        a.arg_cmp_signed(&b)
+            .then_with(|| a.inner(a).cmp(&b.inner(b)))
+    });
+    res
+}
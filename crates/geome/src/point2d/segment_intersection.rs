@@ -0,0 +1,292 @@
+use crate::point2d::Point2D;
+
+/// Counts unordered pairs of closed segments sharing at least one point by the Bentley-Ottmann
+/// sweep. Touching at an endpoint counts as an intersection, and a pair of collinear overlapping
+/// segments counts once. Degenerate segments (single points) are allowed. Intersection points are
+/// handled exactly as rationals, so coordinates must satisfy |x|, |y| <= 10^9.
+///
+/// # Complexity
+/// Time: O((n + k) log n), where k is the number of intersection points.
+pub fn count_intersections(segments: &[(Point2D<i64>, Point2D<i64>)]) -> usize {
+    let segs: Vec<(Point2D<i64>, Point2D<i64>)> = segments
+        .iter()
+        .map(|&(a, b)| {
+            if (a.x(), a.y()) <= (b.x(), b.y()) {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        })
+        .collect();
+    let n = segs.len();
+
+    let mut events: std::collections::BTreeMap<Event, Vec<usize>> =
+        std::collections::BTreeMap::new();
+    for (i, &(a, b)) in segs.iter().enumerate() {
+        events.entry(Event::from_point(a)).or_default().push(i);
+        events.entry(Event::from_point(b)).or_default();
+    }
+
+    let mut status = Treap::new(n);
+    let mut root = NIL;
+    let mut count = 0;
+    while let Some((p, upper)) = events.pop_first() {
+        let side = |i: usize| side(segs[i].0, segs[i].1, &p);
+        let (below, rest) = status.split(root, &|i| side(i) == std::cmp::Ordering::Greater);
+        let (through, above) = status.split(rest, &|i| side(i) == std::cmp::Ordering::Equal);
+        let mut group = Vec::new();
+        status.collect(through, &mut group);
+
+        // Every pair through `p` meets here. A collinear pair shares a whole interval, so it is
+        // counted only at the left end of the overlap, where one of them starts.
+        let total = upper.len() + group.len();
+        count += total * total.saturating_sub(1) / 2;
+        let mut dirs: Vec<(i64, i64)> = group.iter().map(|&i| direction(segs[i])).collect();
+        dirs.sort_unstable();
+        let mut i = 0;
+        while i < dirs.len() {
+            let mut j = i + 1;
+            while j < dirs.len() && dirs[j] == dirs[i] {
+                j += 1;
+            }
+            count -= (j - i) * (j - i - 1) / 2;
+            i = j;
+        }
+
+        let mut mid: Vec<usize> = group
+            .into_iter()
+            .filter(|&i| Event::from_point(segs[i].1) != p)
+            .chain(upper.into_iter().filter(|&i| segs[i].0 != segs[i].1))
+            .collect();
+        mid.sort_unstable_by(|&i, &j| {
+            let (di, dj) = (direction(segs[i]), direction(segs[j]));
+            (di.1 as i128 * dj.0 as i128).cmp(&(dj.1 as i128 * di.0 as i128))
+        });
+        let mut middle = NIL;
+        for &i in mid.iter() {
+            status.reset(i);
+            middle = status.merge(middle, i);
+        }
+
+        let mut find_event = |s: usize, t: usize| {
+            if let Some(q) = intersection(segs[s], segs[t])
+                && q > p
+            {
+                events.entry(q).or_default();
+            }
+        };
+        if mid.is_empty() {
+            if below != NIL && above != NIL {
+                find_event(status.last(below), status.first(above));
+            }
+        } else {
+            if below != NIL {
+                find_event(status.last(below), mid[0]);
+            }
+            if above != NIL {
+                find_event(mid[mid.len() - 1], status.first(above));
+            }
+        }
+        let left = status.merge(below, middle);
+        root = status.merge(left, above);
+    }
+    count
+}
+
+const NIL: usize = !0;
+
+/// A rational point `(x / d, y / d)` with `d > 0` and `gcd(x, y, d) = 1`, ordered by `(x, y)`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Event {
+    x: i128,
+    y: i128,
+    d: i128,
+}
+
+impl Event {
+    fn new(mut x: i128, mut y: i128, mut d: i128) -> Self {
+        if d < 0 {
+            (x, y, d) = (-x, -y, -d);
+        }
+        let g = gcd(gcd(x.unsigned_abs(), y.unsigned_abs()), d as u128) as i128;
+        Self {
+            x: x / g,
+            y: y / g,
+            d: d / g,
+        }
+    }
+
+    fn from_point(p: Point2D<i64>) -> Self {
+        Self {
+            x: p.x() as i128,
+            y: p.y() as i128,
+            d: 1,
+        }
+    }
+}
+
+impl Ord for Event {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        cmp_frac(self.x, self.d, other.x, other.d)
+            .then_with(|| cmp_frac(self.y, self.d, other.y, other.d))
+    }
+}
+
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares `a / b` and `c / d` for `b, d > 0` without overflow by continued fractions.
+fn cmp_frac(a: i128, b: i128, c: i128, d: i128) -> std::cmp::Ordering {
+    let (qa, ra) = (a.div_euclid(b), a.rem_euclid(b));
+    let (qc, rc) = (c.div_euclid(d), c.rem_euclid(d));
+    if qa != qc {
+        return qa.cmp(&qc);
+    }
+    match (ra == 0, rc == 0) {
+        (true, true) => std::cmp::Ordering::Equal,
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        (false, false) => cmp_frac(d, rc, b, ra),
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the side of `p` against the line through `a` and `b`, where `Greater` means `p` is
+/// above (to the left of) the line.
+fn side(a: Point2D<i64>, b: Point2D<i64>, p: &Event) -> std::cmp::Ordering {
+    let dx = (b.x() - a.x()) as i128;
+    let dy = (b.y() - a.y()) as i128;
+    let px = p.x - a.x() as i128 * p.d;
+    let py = p.y - a.y() as i128 * p.d;
+    (dx * py).cmp(&(dy * px))
+}
+
+fn direction((a, b): (Point2D<i64>, Point2D<i64>)) -> (i64, i64) {
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let g = gcd(dx.unsigned_abs() as u128, dy.unsigned_abs() as u128) as i64;
+    if g == 0 { (0, 0) } else { (dx / g, dy / g) }
+}
+
+/// Returns the unique intersection point of two non-parallel segments.
+fn intersection(
+    (a, b): (Point2D<i64>, Point2D<i64>),
+    (c, d): (Point2D<i64>, Point2D<i64>),
+) -> Option<Event> {
+    let r = a.to(b);
+    let s = c.to(d);
+    let ac = a.to(c);
+    let cross =
+        |u: (i64, i64), v: (i64, i64)| u.0 as i128 * v.1 as i128 - u.1 as i128 * v.0 as i128;
+    let (r, s, ac) = ((r.x(), r.y()), (s.x(), s.y()), (ac.x(), ac.y()));
+    let mut den = cross(r, s);
+    if den == 0 {
+        return None;
+    }
+    let mut t = cross(ac, s);
+    let mut u = cross(ac, r);
+    if den < 0 {
+        (den, t, u) = (-den, -t, -u);
+    }
+    if t < 0 || t > den || u < 0 || u > den {
+        return None;
+    }
+    Some(Event::new(
+        a.x() as i128 * den + r.0 as i128 * t,
+        a.y() as i128 * den + r.1 as i128 * t,
+        den,
+    ))
+}
+
+/// A treap keyed implicitly by in-order position, used as the sweep status.
+struct Treap {
+    left: Box<[usize]>,
+    right: Box<[usize]>,
+    priority: Box<[u64]>,
+}
+
+impl Treap {
+    fn new(n: usize) -> Self {
+        let mut x = 0x9e37_79b9_7f4a_7c15u64;
+        let priority = (0..n)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x
+            })
+            .collect();
+        Self {
+            left: vec![NIL; n].into_boxed_slice(),
+            right: vec![NIL; n].into_boxed_slice(),
+            priority,
+        }
+    }
+
+    fn reset(&mut self, t: usize) {
+        self.left[t] = NIL;
+        self.right[t] = NIL;
+    }
+
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.priority[a] > self.priority[b] {
+            self.right[a] = self.merge(self.right[a], b);
+            a
+        } else {
+            self.left[b] = self.merge(a, self.left[b]);
+            b
+        }
+    }
+
+    /// Splits into the prefix where `pred` holds and the rest. `pred` must be monotone.
+    fn split<F: Fn(usize) -> bool>(&mut self, t: usize, pred: &F) -> (usize, usize) {
+        if t == NIL {
+            return (NIL, NIL);
+        }
+        if pred(t) {
+            let (a, b) = self.split(self.right[t], pred);
+            self.right[t] = a;
+            (t, b)
+        } else {
+            let (a, b) = self.split(self.left[t], pred);
+            self.left[t] = b;
+            (a, t)
+        }
+    }
+
+    fn first(&self, mut t: usize) -> usize {
+        while self.left[t] != NIL {
+            t = self.left[t];
+        }
+        t
+    }
+
+    fn last(&self, mut t: usize) -> usize {
+        while self.right[t] != NIL {
+            t = self.right[t];
+        }
+        t
+    }
+
+    fn collect(&self, t: usize, out: &mut Vec<usize>) {
+        if t != NIL {
+            self.collect(self.left[t], out);
+            out.push(t);
+            self.collect(self.right[t], out);
+        }
+    }
+}
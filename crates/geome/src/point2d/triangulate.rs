@@ -0,0 +1,73 @@
+use crate::point2d::Point2D;
+
+/// Triangulates a simple polygon by ear clipping and returns `n - 2` index triples, each in
+/// counter-clockwise order. The vertices must be given in counter-clockwise order and the polygon
+/// must be simple (no self-intersections and no repeated vertices). Collinear consecutive vertices
+/// are allowed. If there are fewer than three vertices returns an empty vector.
+///
+/// # Complexity
+/// Time: O(n^2)
+pub fn triangulate(poly: &[Point2D<i64>]) -> Vec<(usize, usize, usize)> {
+    let n = poly.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut removed = vec![false; n];
+    let is_ear = |prev: &[usize], next: &[usize], removed: &[bool], b: usize| {
+        let (a, c) = (prev[b], next[b]);
+        let (pa, pb, pc) = (poly[a], poly[b], poly[c]);
+        if pa.to(pb).outer(pb.to(pc)) <= 0 {
+            return false;
+        }
+        // No remaining vertex may lie strictly inside the triangle or on the open diagonal `ac`.
+        let mut v = next[c];
+        while v != a {
+            let p = poly[v];
+            if !removed[v]
+                && pa.to(pb).outer(pa.to(p)) > 0
+                && pb.to(pc).outer(pb.to(p)) > 0
+                && pc.to(pa).outer(pc.to(p)) >= 0
+            {
+                return false;
+            }
+            v = next[v];
+        }
+        true
+    };
+    let mut ear: Vec<bool> = (0..n).map(|b| is_ear(&prev, &next, &removed, b)).collect();
+
+    let mut res = Vec::with_capacity(n - 2);
+    let mut cur = 0;
+    let mut remaining = n;
+    let mut stale = 0;
+    while remaining > 3 {
+        if !ear[cur] {
+            cur = next[cur];
+            stale += 1;
+            debug_assert!(
+                stale <= remaining,
+                "no ear found: the polygon must be simple and CCW"
+            );
+            if stale > remaining {
+                break;
+            }
+            continue;
+        }
+        let (a, c) = (prev[cur], next[cur]);
+        res.push((a, cur, c));
+        removed[cur] = true;
+        next[a] = c;
+        prev[c] = a;
+        remaining -= 1;
+        ear[a] = is_ear(&prev, &next, &removed, a);
+        ear[c] = is_ear(&prev, &next, &removed, c);
+        cur = c;
+        stale = 0;
+    }
+    if remaining == 3 {
+        res.push((prev[cur], cur, next[cur]));
+    }
+    res
+}
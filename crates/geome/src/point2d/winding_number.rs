@@ -0,0 +1,27 @@
+use crate::point2d::Point2D;
+
+/// Returns the signed number of times the closed polygonal chain `poly` winds around `p`, positive
+/// for counter-clockwise turns. The chain may self-intersect: `p` is inside under the nonzero rule
+/// if the result is nonzero and under the even-odd rule if it is odd. `p` must not lie on the
+/// chain.
+///
+/// # Complexity
+/// Time: O(n)
+pub fn winding_number(poly: &[Point2D<i64>], p: Point2D<i64>) -> i64 {
+    let n = poly.len();
+    let mut res = 0;
+    for i in 0..n {
+        let (a, b) = (poly[i], poly[(i + 1) % n]);
+        let side = a.to(b).outer(a.to(p));
+        // An edge crossing the horizontal ray to the right of `p` upward counts +1 and downward
+        // counts -1, with the lower endpoint included and the upper excluded.
+        if a.y() <= p.y() {
+            if b.y() > p.y() && side > 0 {
+                res += 1;
+            }
+        } else if b.y() <= p.y() && side < 0 {
+            res -= 1;
+        }
+    }
+    res
+}
@@ -58,7 +58,7 @@ impl<
         + std::ops::Mul<Output = T>,
 > Vector2D<T>
 {
-    /// Returns inner product of vectors.
+    /// Returns inner product of vectors, `self.x * rhs.x + self.y * rhs.y`.
     ///
     /// # Complexity
     /// Time: O(1)
@@ -66,7 +66,8 @@ impl<
         self.x() * rhs.x() + self.y() * rhs.y()
     }
 
-    /// Returns outer product of vectors.
+    /// Returns outer product of vectors, `self.x * rhs.y - self.y * rhs.x`, which is positive when
+    /// `rhs` is counter-clockwise from `self`.
     ///
     /// # Complexity
     /// Time: O(1)
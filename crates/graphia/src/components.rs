@@ -0,0 +1,46 @@
+use crate::csr::Csr;
+
+/// Computes connected components of an undirected graph by iterative DFS. Returns `(count,
+/// comp)` where `comp[v]` is the component index of vertex `v`, numbered in order of the smallest
+/// vertex.
+///
+/// # Complexity
+/// Time: O(n + m)
+pub fn connected_components<W: Copy>(graph: &Csr<W>) -> (usize, Vec<usize>) {
+    let n = graph.num_vertices();
+    let mut comp = vec![!0; n];
+    let mut count = 0;
+    let mut stack = Vec::new();
+    unsafe {
+        let c = comp.as_mut_ptr();
+        for s in 0..n {
+            if *c.add(s) != !0 {
+                continue;
+            }
+            *c.add(s) = count;
+            stack.push(s);
+            while let Some(u) = stack.pop() {
+                for &(v, _) in graph.adj(u) {
+                    if *c.add(v) == !0 {
+                        *c.add(v) = count;
+                        stack.push(v);
+                    }
+                }
+            }
+            count += 1;
+        }
+    }
+    (count, comp)
+}
+
+/// Returns the size of each component given the output of `connected_components`.
+///
+/// # Complexity
+/// Time: O(n)
+pub fn component_sizes(count: usize, comp: &[usize]) -> Vec<usize> {
+    let mut size = vec![0; count];
+    for &c in comp {
+        size[c] += 1;
+    }
+    size
+}
@@ -166,6 +166,36 @@ impl<W: Copy> Csr<W> {
         }
     }
 
+    /// Constructs the quotient graph that merges all vertices sharing a label, where `labels[v]`
+    /// is the new vertex of `v` and the number of new vertices is `max(labels) + 1`. Every edge
+    /// `(u, v, w)` becomes `(labels[u], labels[v], w)`, so parallel edges are kept with their own
+    /// weights. If `drop_self_loops` is set, edges inside a single label are removed.
+    ///
+    /// # Complexity
+    /// Time: O(n + m)
+    pub fn contract(&self, labels: &[usize], drop_self_loops: bool) -> Self {
+        let n = self.num_vertices();
+        debug_assert_eq!(
+            n,
+            labels.len(),
+            "length mismatch: n={}, labels={}",
+            n,
+            labels.len()
+        );
+        let k = labels.iter().max().map_or(0, |&x| x + 1);
+        let mut edges = Vec::with_capacity(self.num_edges());
+        for u in 0..n {
+            let lu = labels[u];
+            for &(v, w) in self.adj(u) {
+                let lv = labels[v];
+                if !drop_self_loops || lu != lv {
+                    edges.push((lu, lv, w));
+                }
+            }
+        }
+        Self::from_directed_weighted(k, &edges)
+    }
+
     /// Returns the number of vertices.
     ///
     /// # Complexity
@@ -266,6 +296,83 @@ impl Csr<()> {
     }
 }
 
+/// A builder for a directed CSR which accepts edges one at a time.
+///
+/// # Complexity
+/// Space: O(n + m)
+pub struct CsrBuilder<W: Copy> {
+    n: usize,
+    edges: Vec<(usize, usize, W)>,
+}
+
+impl<W: Copy> CsrBuilder<W> {
+    /// Creates a new empty builder with `n` vertices.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Creates a new empty builder with `n` vertices and edge capacity `m`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn with_capacity(n: usize, m: usize) -> Self {
+        Self {
+            n,
+            edges: Vec::with_capacity(m),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more edges.
+    ///
+    /// # Complexity
+    /// Time: O(m)
+    pub fn reserve(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+    }
+
+    /// Adds a directed edge from `u` to `v` with weight `w`.
+    ///
+    /// # Complexity
+    /// Time: amortized O(1)
+    pub fn add_edge(&mut self, u: usize, v: usize, w: W) {
+        debug_assert!(
+            u < self.n,
+            "source vertex out of bounds: u={}, n={}",
+            u,
+            self.n
+        );
+        debug_assert!(
+            v < self.n,
+            "destination vertex out of bounds: v={}, n={}",
+            v,
+            self.n
+        );
+        self.edges.push((u, v, w));
+    }
+
+    /// Builds the CSR. Edges of each vertex keep the order in which they were added.
+    ///
+    /// # Complexity
+    /// Time: O(n + m)
+    pub fn build(self) -> Csr<W> {
+        Csr::from_directed_weighted(self.n, &self.edges)
+    }
+
+    /// Returns the number of edges added so far.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+}
+
 /// A trait for converting edge weights into distances for graph algorithms. For unweighted graphs
 /// (`Csr<()>`), edges are treated as having distance 1.
 pub trait EdgeWeight: Copy {
@@ -0,0 +1,111 @@
+/// A residual graph structure whose edges also carry a cost per unit of flow. Reverse edges have
+/// the negated cost.
+///
+/// # Complexity
+/// Space: O(n + m)
+pub struct CostResidualGraph<Cap, Cost> {
+    n: usize,
+    pub(crate) offset: Box<[u32]>,
+    pub(crate) edge: Box<[(u32, u32, Cap, Cost)]>,
+    csr_idx: Box<[u32]>,
+}
+
+impl<Cap: Copy + Default, Cost: Copy + std::ops::Neg<Output = Cost>> CostResidualGraph<Cap, Cost> {
+    /// Creates a cost residual graph from directed edge lists `(u, v, cap, cost)`. Edges are
+    /// numbered in input order.
+    ///
+    /// # Complexity
+    /// Time: O(n + m)
+    pub fn from_directed(n: usize, edges: &[(usize, usize, Cap, Cost)]) -> Self {
+        let m = edges.len();
+        let mut offset = vec![0; n + 1];
+        let mut edge: Vec<std::mem::MaybeUninit<(u32, u32, Cap, Cost)>> =
+            Vec::with_capacity(m << 1);
+        let mut csr_idx = Vec::with_capacity(m);
+        unsafe {
+            let offset = offset.as_mut_ptr();
+            for &(u, v, _, _) in edges {
+                debug_assert!(u < n, "source vertex out of bounds: u={}, n={}", u, n);
+                debug_assert!(v < n, "destination vertex out of bounds: v={}, n={}", v, n);
+                *offset.add(u + 1) += 1;
+                *offset.add(v + 1) += 1;
+            }
+            for i in 1..=n {
+                *offset.add(i) += *offset.add(i - 1);
+            }
+            edge.set_len(m << 1);
+            let edge = edge.as_mut_ptr() as *mut (u32, u32, Cap, Cost);
+            for &(u, v, c, w) in edges {
+                let pos_u = *offset.add(u);
+                *offset.add(u) += 1;
+                let pos_v = *offset.add(v);
+                *offset.add(v) += 1;
+                edge.add(pos_u as usize).write((v as u32, pos_v, c, w));
+                edge.add(pos_v as usize)
+                    .write((u as u32, pos_u, Cap::default(), -w));
+                csr_idx.push(pos_u);
+            }
+            std::ptr::copy(offset, offset.add(1), n);
+            *offset = 0;
+        }
+        Self {
+            n,
+            offset: offset.into_boxed_slice(),
+            edge: unsafe {
+                Box::from_raw(Box::into_raw(edge.into_boxed_slice()) as *mut [(u32, u32, Cap, Cost)])
+            },
+            csr_idx: csr_idx.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the flow on the forward edge `e`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn flow(&self, e: usize) -> Cap {
+        debug_assert!(
+            e < self.csr_idx.len(),
+            "edge is out of bounds: e={}, num_edges={}",
+            e,
+            self.csr_idx.len(),
+        );
+        let (_, rev, _, _) = self.edge[self.csr_idx[e] as usize];
+        self.edge[rev as usize].2
+    }
+
+    /// Returns the number of vertices.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn num_vertices(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of directed edges (including reverse edges).
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn num_edges(&self) -> usize {
+        self.edge.len()
+    }
+}
+
+impl<Cap: Copy + Default + std::ops::Add<Output = Cap>, Cost: Copy + std::ops::Neg<Output = Cost>>
+    CostResidualGraph<Cap, Cost>
+{
+    /// Returns the initial capacity on the forward edge `e`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn initial_cap(&self, e: usize) -> Cap {
+        debug_assert!(
+            e < self.csr_idx.len(),
+            "edge is out of bounds: e={}, num_edges={}",
+            e,
+            self.csr_idx.len(),
+        );
+        let idx = self.csr_idx[e] as usize;
+        let (_, rev, cap, _) = self.edge[idx];
+        cap + self.edge[rev as usize].2
+    }
+}
@@ -0,0 +1,115 @@
+use crate::flow::CostResidualGraph;
+
+/// Pushes flow from `s` to `t` along shortest augmenting paths until `flow_limit` units are sent
+/// or `t` becomes unreachable, and returns the flow and its total cost. The result is a cheapest
+/// flow of its amount, so `flow_limit` set to the maximum gives a min-cost max-flow and a smaller
+/// one the min cost to send exactly that much, if the returned flow reaches it. Negative costs
+/// are allowed as long as there is no negative cycle: the first potentials come from a
+/// Bellman-Ford (SPFA) pass, and later paths are found by Dijkstra's algorithm on reduced costs.
+///
+/// # Complexity
+/// Time: O(nm + F (n + m) log m), where F is the number of augmentations.
+pub fn min_cost_flow<Cap, Cost>(
+    g: &mut CostResidualGraph<Cap, Cost>,
+    s: usize,
+    t: usize,
+    flow_limit: Cap,
+) -> (Cap, Cost)
+where
+    Cap: Copy + Default + Ord + std::ops::Add<Output = Cap> + std::ops::Sub<Output = Cap>,
+    Cost: Copy
+        + Default
+        + Ord
+        + std::ops::Add<Output = Cost>
+        + std::ops::Sub<Output = Cost>
+        + std::ops::Neg<Output = Cost>
+        + std::ops::Mul<Cap, Output = Cost>,
+{
+    let n = g.num_vertices();
+    debug_assert!(s < n, "source vertex out of bounds: s={}, n={}", s, n);
+    debug_assert!(t < n, "destination vertex out of bounds: t={}, n={}", t, n);
+    let zero = Cap::default();
+    let mut flow = Cap::default();
+    let mut cost = Cost::default();
+    if s == t {
+        return (flow, cost);
+    }
+
+    // Potentials of the vertices reachable from `s`, which stay valid as the residual graph
+    // changes only among them.
+    let mut potential: Vec<Option<Cost>> = vec![None; n];
+    potential[s] = Some(Cost::default());
+    let mut queue = std::collections::VecDeque::from([s]);
+    let mut in_queue = vec![false; n];
+    in_queue[s] = true;
+    while let Some(u) = queue.pop_front() {
+        in_queue[u] = false;
+        let du = potential[u].unwrap();
+        for &(v, _, cap, w) in &g.edge[g.offset[u] as usize..g.offset[u + 1] as usize] {
+            let v = v as usize;
+            if cap > zero && potential[v].is_none_or(|x| du + w < x) {
+                potential[v] = Some(du + w);
+                if !in_queue[v] {
+                    in_queue[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+
+    let mut dist: Vec<Option<Cost>> = vec![None; n];
+    let mut prev = vec![!0u32; n];
+    let mut heap = std::collections::BinaryHeap::new();
+    while flow < flow_limit {
+        dist.fill(None);
+        dist[s] = Some(Cost::default());
+        heap.push(std::cmp::Reverse((Cost::default(), s)));
+        while let Some(std::cmp::Reverse((d, u))) = heap.pop() {
+            if dist[u].is_some_and(|x| x < d) {
+                continue;
+            }
+            let hu = potential[u].unwrap();
+            for i in g.offset[u] as usize..g.offset[u + 1] as usize {
+                let (v, _, cap, w) = g.edge[i];
+                let v = v as usize;
+                if cap == zero {
+                    continue;
+                }
+                let nd = d + w + hu - potential[v].unwrap();
+                if dist[v].is_none_or(|x| nd < x) {
+                    dist[v] = Some(nd);
+                    prev[v] = i as u32;
+                    heap.push(std::cmp::Reverse((nd, v)));
+                }
+            }
+        }
+        if dist[t].is_none() {
+            break;
+        }
+        for (h, d) in potential.iter_mut().zip(dist.iter()) {
+            if let (Some(h), Some(d)) = (h.as_mut(), d) {
+                *h = *h + *d;
+            }
+        }
+
+        let mut push = flow_limit - flow;
+        let mut v = t;
+        while v != s {
+            let i = prev[v] as usize;
+            push = push.min(g.edge[i].2);
+            let (_, rev, _, _) = g.edge[i];
+            v = g.edge[rev as usize].0 as usize;
+        }
+        let mut v = t;
+        while v != s {
+            let i = prev[v] as usize;
+            let (_, rev, _, _) = g.edge[i];
+            g.edge[i].2 = g.edge[i].2 - push;
+            g.edge[rev as usize].2 = g.edge[rev as usize].2 + push;
+            v = g.edge[rev as usize].0 as usize;
+        }
+        flow = flow + push;
+        cost = cost + (potential[t].unwrap() - potential[s].unwrap()) * push;
+    }
+    (flow, cost)
+}
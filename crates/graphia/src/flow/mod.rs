@@ -1,5 +1,11 @@
+mod cost_residual_graph;
 mod dinic;
+mod disjoint_paths;
+mod min_cost_flow;
 mod residual_graph;
 
+pub use cost_residual_graph::CostResidualGraph;
 pub use dinic::dinic;
+pub use disjoint_paths::{edge_disjoint_paths, vertex_disjoint_paths};
+pub use min_cost_flow::min_cost_flow;
 pub use residual_graph::ResidualGraph;
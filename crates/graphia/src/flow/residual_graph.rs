@@ -44,16 +44,30 @@ impl<Cap: Copy + Default> ResidualGraph<Cap> {
     /// # Complexity
     /// Time: O(n + m)
     pub fn from_directed(n: usize, edges: &[(usize, usize, Cap)]) -> Self {
-        let m = edges.len();
+        for &(u, v, _) in edges {
+            debug_assert!(u < n, "source vertex out of bounds: u={}, n={}", u, n);
+            debug_assert!(v < n, "destination vertex out of bounds: v={}, n={}", v, n);
+        }
+        Self::assemble(
+            n,
+            edges.len(),
+            edges.iter().map(|&(u, v, c)| (u, v, c, Cap::default())),
+        )
+    }
 
+    /// Lays out `m` edges `(u, v, forward residual, reverse residual)` in CSR order, numbering
+    /// them in iteration order.
+    fn assemble<I: Iterator<Item = (usize, usize, Cap, Cap)> + Clone>(
+        n: usize,
+        m: usize,
+        arcs: I,
+    ) -> Self {
         let mut offset = vec![0; n + 1];
         let mut edge: Vec<std::mem::MaybeUninit<(u32, u32, Cap)>> = Vec::with_capacity(m << 1);
         let mut csr_idx = Vec::with_capacity(m);
         unsafe {
             let offset = offset.as_mut_ptr();
-            for &(u, v, _) in edges {
-                debug_assert!(u < n, "source vertex out of bounds: u={}, n={}", u, n);
-                debug_assert!(v < n, "destination vertex out of bounds: v={}, n={}", v, n);
+            for (u, v, _, _) in arcs.clone() {
                 *offset.add(u + 1) += 1;
                 *offset.add(v + 1) += 1;
             }
@@ -62,14 +76,13 @@ impl<Cap: Copy + Default> ResidualGraph<Cap> {
             }
             edge.set_len(m << 1);
             let edge = edge.as_mut_ptr() as *mut (u32, u32, Cap);
-            for &(u, v, c) in edges {
+            for (u, v, c, r) in arcs {
                 let pos_u = *offset.add(u);
                 *offset.add(u) += 1;
                 let pos_v = *offset.add(v);
                 *offset.add(v) += 1;
                 edge.add(pos_u as usize).write((v as u32, pos_v, c));
-                edge.add(pos_v as usize)
-                    .write((u as u32, pos_u, Cap::default()));
+                edge.add(pos_v as usize).write((u as u32, pos_u, r));
                 csr_idx.push(pos_u);
             }
             std::ptr::copy(offset, offset.add(1), n);
@@ -109,12 +122,22 @@ impl<Cap: Copy + Default> ResidualGraph<Cap> {
         idx
     }
 
-    /// Builds the CSR pending edges. Existing CSR is rebuilt.
+    /// Builds the CSR pending edges. Existing CSR is rebuilt, keeping the index and the current
+    /// flow of every edge.
     ///
     /// # Complexity
     /// Time: O(n + m)
     pub fn build(&mut self) {
-        todo!();
+        let mut arcs = Vec::with_capacity(self.csr_idx.len() + self.pending.len());
+        for &idx in self.csr_idx.iter() {
+            let (v, rev, c) = self.edge[idx as usize];
+            let (u, _, r) = self.edge[rev as usize];
+            arcs.push((u as usize, v as usize, c, r));
+        }
+        for &(u, v, c) in self.pending.iter() {
+            arcs.push((u, v, c, Cap::default()));
+        }
+        *self = Self::assemble(self.n, arcs.len(), arcs.into_iter());
     }
 
     /// Returns the flow on the forward edge `e`.
@@ -170,7 +193,8 @@ impl<Cap: Copy + Default + std::ops::Add<Output = Cap>> ResidualGraph<Cap> {
                 (*edge.add(rev as usize)).2 = Cap::default();
             }
         }
-        if !self.pending.is_empty() {
+        // A graph that was never built has no offsets yet, even without pending edges.
+        if !self.pending.is_empty() || self.offset.is_empty() {
             self.build();
         }
     }
@@ -196,3 +220,36 @@ impl<Cap: Copy + Default + std::ops::Add<Output = Cap>> ResidualGraph<Cap> {
         }
     }
 }
+
+impl<Cap: Copy + Default + PartialOrd> ResidualGraph<Cap> {
+    /// Returns the vertices reachable from `s` along edges with positive residual capacity. After
+    /// a maximum flow from `s` this is the source side of a minimum cut, and the cut edges are
+    /// those going from a reachable vertex to an unreachable one. Pending edges are built first.
+    ///
+    /// # Complexity
+    /// Time: O(n + m)
+    pub fn min_cut(&mut self, s: usize) -> Vec<bool> {
+        debug_assert!(
+            s < self.n,
+            "source vertex out of bounds: s={}, n={}",
+            s,
+            self.n
+        );
+        // A graph that was never built has no offsets yet, even without pending edges.
+        if !self.pending.is_empty() || self.offset.is_empty() {
+            self.build();
+        }
+        let mut seen = vec![false; self.n];
+        let mut stack = vec![s];
+        seen[s] = true;
+        while let Some(u) = stack.pop() {
+            for &(v, _, cap) in &self.edge[self.offset[u] as usize..self.offset[u + 1] as usize] {
+                if cap > Cap::default() && !seen[v as usize] {
+                    seen[v as usize] = true;
+                    stack.push(v as usize);
+                }
+            }
+        }
+        seen
+    }
+}
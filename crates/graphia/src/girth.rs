@@ -0,0 +1,68 @@
+use crate::csr::Csr;
+
+/// Returns the length of a shortest cycle of an undirected unweighted graph, or `None` if the
+/// graph is a forest. A self-loop is a cycle of length 1 and a pair of parallel edges is a cycle
+/// of length 2. Otherwise a BFS runs from every vertex, and a non-tree edge `(u, v)` closes a
+/// cycle of length at most `dist[u] + dist[v] + 1`, which is exact from a vertex on a shortest
+/// cycle.
+///
+/// # Complexity
+/// Time: O(nm)
+pub fn girth<W: Copy>(graph: &Csr<W>) -> Option<usize> {
+    let n = graph.num_vertices();
+    let mut seen = vec![!0; n];
+    for u in 0..n {
+        for &(v, _) in graph.adj(u) {
+            if v == u {
+                return Some(1);
+            }
+        }
+    }
+    for u in 0..n {
+        for &(v, _) in graph.adj(u) {
+            if seen[v] == u {
+                return Some(2);
+            }
+            seen[v] = u;
+        }
+    }
+
+    let mut res = usize::MAX;
+    let mut dist = vec![!0; n];
+    let mut parent = vec![!0; n];
+    let mut queue = Vec::with_capacity(n);
+    for s in 0..n {
+        dist[s] = 0;
+        queue.push(s);
+        let mut head = 0;
+        'bfs: while head < queue.len() {
+            let u = queue[head];
+            head += 1;
+            // No shorter cycle can be found once the BFS is this deep.
+            if 2 * dist[u] + 1 >= res {
+                break;
+            }
+            for &(v, _) in graph.adj(u) {
+                if dist[v] == !0 {
+                    dist[v] = dist[u] + 1;
+                    parent[v] = u;
+                    queue.push(v);
+                } else if parent[u] != v {
+                    res = res.min(dist[u] + dist[v] + 1);
+                    if res == 3 {
+                        break 'bfs;
+                    }
+                }
+            }
+        }
+        for &v in queue.iter() {
+            dist[v] = !0;
+            parent[v] = !0;
+        }
+        queue.clear();
+        if res == 3 {
+            break;
+        }
+    }
+    (res != usize::MAX).then_some(res)
+}
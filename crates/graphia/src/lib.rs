@@ -1,4 +1,12 @@
+pub mod components;
 pub mod csr;
 pub mod flow;
+pub mod girth;
+pub mod lowlink;
+pub mod matching;
+pub mod mst;
 pub mod scc;
+pub mod shortest_path;
+pub mod spanning_tree_count;
+pub mod topological_sort;
 pub mod tree;
@@ -0,0 +1,89 @@
+use crate::csr::Csr;
+
+/// Runs an iterative DFS over an undirected graph and returns `(ord, low, tree)`, where `tree`
+/// lists the DFS tree edges `(parent, child)`. Only the first copy of the edge to the parent is
+/// the tree edge, so parallel edges act as back edges.
+fn lowlink(graph: &Csr<()>) -> (Vec<usize>, Vec<usize>, Vec<(usize, usize)>) {
+    let n = graph.num_vertices();
+    let mut ord = vec![!0; n];
+    let mut low = vec![0; n];
+    let mut tree = Vec::with_capacity(n);
+    // Frames of (vertex, parent, next edge index, whether the parent edge was skipped).
+    let mut stack: Vec<(usize, usize, usize, bool)> = Vec::new();
+    let mut time = 0;
+    for r in 0..n {
+        if ord[r] != !0 {
+            continue;
+        }
+        ord[r] = time;
+        low[r] = time;
+        time += 1;
+        stack.push((r, !0, 0, false));
+        while let Some(&mut (v, p, ref mut i, ref mut skipped)) = stack.last_mut() {
+            let adj = graph.adj(v);
+            if *i < adj.len() {
+                let c = adj[*i].0;
+                *i += 1;
+                if c == p && !*skipped {
+                    *skipped = true;
+                } else if ord[c] == !0 {
+                    ord[c] = time;
+                    low[c] = time;
+                    time += 1;
+                    tree.push((v, c));
+                    stack.push((c, v, 0, false));
+                } else {
+                    low[v] = low[v].min(ord[c]);
+                }
+                continue;
+            }
+            stack.pop();
+            if p != !0 {
+                low[p] = low[p].min(low[v]);
+            }
+        }
+    }
+    (ord, low, tree)
+}
+
+/// Returns the bridges of an undirected graph as pairs `(u, v)` with `u < v`, sorted. A pair of
+/// parallel edges is never a bridge.
+///
+/// # Complexity
+/// Time: O(n + m)
+pub fn bridges(graph: &Csr<()>) -> Vec<(usize, usize)> {
+    let (ord, low, tree) = lowlink(graph);
+    let mut res: Vec<(usize, usize)> = tree
+        .into_iter()
+        .filter(|&(p, c)| low[c] > ord[p])
+        .map(|(p, c)| (p.min(c), p.max(c)))
+        .collect();
+    res.sort_unstable();
+    res
+}
+
+/// Returns the articulation points of an undirected graph in increasing order.
+///
+/// # Complexity
+/// Time: O(n + m)
+pub fn articulation_points(graph: &Csr<()>) -> Vec<usize> {
+    let n = graph.num_vertices();
+    let (ord, low, tree) = lowlink(graph);
+    // A DFS root is a cut vertex iff it has at least two children, and any other vertex iff some
+    // child subtree cannot reach above it.
+    let mut is_root = vec![true; n];
+    let mut children = vec![0; n];
+    for &(p, c) in tree.iter() {
+        is_root[c] = false;
+        children[p] += 1;
+    }
+    let mut is_cut = vec![false; n];
+    for &(p, c) in tree.iter() {
+        if !is_root[p] && low[c] >= ord[p] {
+            is_cut[p] = true;
+        }
+    }
+    (0..n)
+        .filter(|&v| is_cut[v] || (is_root[v] && children[v] >= 2))
+        .collect()
+}
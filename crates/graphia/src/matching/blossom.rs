@@ -0,0 +1,151 @@
+/// Computes a maximum matching of an undirected general graph on `n` vertices by Edmonds'
+/// blossom algorithm, returning the partner of each vertex. Odd cycles found while searching for
+/// an augmenting path are contracted into their base. Self-loops are ignored.
+///
+/// # Complexity
+/// Time: O(n^3)
+pub fn general_matching(n: usize, edges: &[(usize, usize)]) -> Vec<Option<usize>> {
+    let mut adj = vec![Vec::new(); n];
+    for &(u, v) in edges {
+        debug_assert!(
+            u < n && v < n,
+            "vertex out of bounds: u={}, v={}, n={}",
+            u,
+            v,
+            n,
+        );
+        if u != v {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+    }
+    let mut b = Blossom {
+        adj,
+        mate: vec![!0; n],
+        parent: vec![!0; n],
+        base: (0..n).collect(),
+        used: vec![false; n],
+        in_blossom: vec![false; n],
+        queue: std::collections::VecDeque::new(),
+    };
+    // A greedy matching first leaves fewer augmentations.
+    for u in 0..n {
+        if b.mate[u] == !0
+            && let Some(&v) = b.adj[u].iter().find(|&&v| b.mate[v] == !0)
+        {
+            b.mate[u] = v;
+            b.mate[v] = u;
+        }
+    }
+    for root in 0..n {
+        if b.mate[root] != !0 {
+            continue;
+        }
+        let mut v = b.find_path(root);
+        while v != !0 {
+            let pv = b.parent[v];
+            let next = b.mate[pv];
+            b.mate[v] = pv;
+            b.mate[pv] = v;
+            v = next;
+        }
+    }
+    b.mate
+        .into_iter()
+        .map(|v| if v == !0 { None } else { Some(v) })
+        .collect()
+}
+
+struct Blossom {
+    adj: Vec<Vec<usize>>,
+    mate: Vec<usize>,
+    // The vertex before each odd vertex on its alternating path to the root.
+    parent: Vec<usize>,
+    base: Vec<usize>,
+    // Whether each vertex is even, that is in the queue at some point.
+    used: Vec<bool>,
+    in_blossom: Vec<bool>,
+    queue: std::collections::VecDeque<usize>,
+}
+
+impl Blossom {
+    /// Grows an alternating tree from the unmatched `root` and returns the unmatched vertex ending
+    /// an augmenting path, or `!0` if there is none.
+    fn find_path(&mut self, root: usize) -> usize {
+        let n = self.mate.len();
+        self.used.fill(false);
+        self.parent.fill(!0);
+        for (i, b) in self.base.iter_mut().enumerate() {
+            *b = i;
+        }
+        self.used[root] = true;
+        self.queue.clear();
+        self.queue.push_back(root);
+        while let Some(v) = self.queue.pop_front() {
+            for i in 0..self.adj[v].len() {
+                let to = self.adj[v][i];
+                if self.base[v] == self.base[to] || self.mate[v] == to {
+                    continue;
+                }
+                if to == root || (self.mate[to] != !0 && self.parent[self.mate[to]] != !0) {
+                    // `to` is even as well, so the edge closes an odd cycle.
+                    let cur = self.lca(v, to);
+                    self.in_blossom.fill(false);
+                    self.mark_path(v, cur, to);
+                    self.mark_path(to, cur, v);
+                    for u in 0..n {
+                        if self.in_blossom[self.base[u]] {
+                            self.base[u] = cur;
+                            if !self.used[u] {
+                                self.used[u] = true;
+                                self.queue.push_back(u);
+                            }
+                        }
+                    }
+                } else if self.parent[to] == !0 {
+                    self.parent[to] = v;
+                    if self.mate[to] == !0 {
+                        return to;
+                    }
+                    let m = self.mate[to];
+                    self.used[m] = true;
+                    self.queue.push_back(m);
+                }
+            }
+        }
+        !0
+    }
+
+    /// Returns the base of the blossom formed by the tree paths of even vertices `a` and `b`.
+    fn lca(&self, mut a: usize, mut b: usize) -> usize {
+        let mut seen = vec![false; self.mate.len()];
+        loop {
+            a = self.base[a];
+            seen[a] = true;
+            if self.mate[a] == !0 {
+                break;
+            }
+            a = self.parent[self.mate[a]];
+        }
+        loop {
+            b = self.base[b];
+            if seen[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b]];
+        }
+    }
+
+    /// Marks the blossoms on the path from `v` down to base `b`, pointing the parents of the odd
+    /// vertices along it back through `child` so that the cycle can be traversed either way.
+    fn mark_path(&mut self, mut v: usize, b: usize, mut child: usize) {
+        while self.base[v] != b {
+            let m = self.mate[v];
+            self.in_blossom[self.base[v]] = true;
+            self.in_blossom[self.base[m]] = true;
+            self.parent[v] = child;
+            child = m;
+            v = self.parent[m];
+        }
+    }
+}
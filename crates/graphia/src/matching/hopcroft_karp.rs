@@ -0,0 +1,115 @@
+/// Computes a maximum matching of a bipartite graph with `left_n` left and `right_n` right
+/// vertices by the Hopcroft-Karp algorithm. Edges are `(left, right)` pairs, and duplicates are
+/// allowed. Returns the size of the matching and the right partner of each left vertex.
+///
+/// # Complexity
+/// Time: O(m sqrt(n))
+pub fn bipartite_matching(
+    left_n: usize,
+    right_n: usize,
+    edges: &[(usize, usize)],
+) -> (usize, Vec<Option<usize>>) {
+    let mut offset = vec![0u32; left_n + 1];
+    for &(u, v) in edges {
+        debug_assert!(
+            u < left_n,
+            "left vertex out of bounds: u={}, left_n={}",
+            u,
+            left_n
+        );
+        debug_assert!(
+            v < right_n,
+            "right vertex out of bounds: v={}, right_n={}",
+            v,
+            right_n
+        );
+        offset[u + 1] += 1;
+    }
+    for i in 1..=left_n {
+        offset[i] += offset[i - 1];
+    }
+    let mut adj = vec![0u32; edges.len()];
+    let mut pos = offset.clone();
+    for &(u, v) in edges {
+        adj[pos[u] as usize] = v as u32;
+        pos[u] += 1;
+    }
+
+    let mut match_left = vec![!0u32; left_n];
+    let mut match_right = vec![!0u32; right_n];
+    let mut dist = vec![u32::MAX; left_n];
+    let mut iter = vec![0u32; left_n];
+    let mut queue = Vec::with_capacity(left_n);
+    let mut stack = Vec::new();
+    let mut size = 0;
+    loop {
+        // Layers the left vertices by alternating distance from the unmatched ones.
+        queue.clear();
+        for u in 0..left_n {
+            if match_left[u] == !0 {
+                dist[u] = 0;
+                queue.push(u);
+            } else {
+                dist[u] = u32::MAX;
+            }
+        }
+        let mut found = false;
+        let mut head = 0;
+        while head < queue.len() {
+            let u = queue[head];
+            head += 1;
+            for &v in &adj[offset[u] as usize..offset[u + 1] as usize] {
+                let w = match_right[v as usize];
+                if w == !0 {
+                    found = true;
+                } else if dist[w as usize] == u32::MAX {
+                    dist[w as usize] = dist[u] + 1;
+                    queue.push(w as usize);
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+
+        // Finds vertex-disjoint shortest augmenting paths along the layers.
+        iter.copy_from_slice(&offset[..left_n]);
+        for r in 0..left_n {
+            if match_left[r] != !0 {
+                continue;
+            }
+            stack.clear();
+            stack.push(r);
+            while let Some(&u) = stack.last() {
+                if iter[u] == offset[u + 1] {
+                    dist[u] = u32::MAX;
+                    stack.pop();
+                    if let Some(&p) = stack.last() {
+                        iter[p] += 1;
+                    }
+                    continue;
+                }
+                let v = adj[iter[u] as usize];
+                let w = match_right[v as usize];
+                if w == !0 {
+                    for &u in stack.iter() {
+                        let v = adj[iter[u] as usize];
+                        match_left[u] = v;
+                        match_right[v as usize] = u as u32;
+                    }
+                    size += 1;
+                    break;
+                } else if dist[w as usize] == dist[u] + 1 {
+                    stack.push(w as usize);
+                } else {
+                    iter[u] += 1;
+                }
+            }
+        }
+    }
+    let res = match_left
+        .into_iter()
+        .map(|v| if v == !0 { None } else { Some(v as usize) })
+        .collect();
+    (size, res)
+}
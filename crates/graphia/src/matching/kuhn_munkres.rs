@@ -0,0 +1,71 @@
+use algebrae::linear::Matrix;
+
+/// Returns the maximum total weight of a perfect matching on the complete bipartite graph whose
+/// weight between left vertex `i` and right vertex `j` is `weight[i][j]`, together with the right
+/// vertex matched to each left vertex. Runs the Hungarian algorithm with potentials on the negated
+/// weights. A missing edge should be given a large negative weight such as `-(1 << 40)` rather
+/// than `i64::MIN`, since the total is returned as `i64` and must not overflow.
+///
+/// # Complexity
+/// Time: O(n^3)
+pub fn kuhn_munkres(weight: &Matrix<i64>) -> (i64, Vec<usize>) {
+    debug_assert!(weight.is_square(), "weight must be square");
+    let n = weight.h();
+    // Row and column potentials, 1-indexed with index 0 as the virtual column.
+    let mut u = vec![0i128; n + 1];
+    let mut v = vec![0i128; n + 1];
+    // p[j] is the row matched to column j, and way[j] the previous column on the augmenting path.
+    let mut p = vec![0usize; n + 1];
+    let mut way = vec![0usize; n + 1];
+    let mut minv = vec![0i128; n + 1];
+    let mut used = vec![false; n + 1];
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        minv.fill(i128::MAX);
+        used.fill(false);
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = i128::MAX;
+            let mut j1 = 0;
+            for j in 1..=n {
+                if !used[j] {
+                    let cur = -(weight[i0 - 1][j - 1] as i128) - u[i0] - v[j];
+                    if cur < minv[j] {
+                        minv[j] = cur;
+                        way[j] = j0;
+                    }
+                    if minv[j] < delta {
+                        delta = minv[j];
+                        j1 = j;
+                    }
+                }
+            }
+            for j in 0..=n {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    minv[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assign = vec![0; n];
+    for j in 1..=n {
+        assign[p[j] - 1] = j - 1;
+    }
+    let total = (0..n).map(|i| weight[i][assign[i]]).sum();
+    (total, assign)
+}
@@ -0,0 +1,7 @@
+mod blossom;
+mod hopcroft_karp;
+mod kuhn_munkres;
+
+pub use blossom::general_matching;
+pub use hopcroft_karp::bipartite_matching;
+pub use kuhn_munkres::kuhn_munkres;
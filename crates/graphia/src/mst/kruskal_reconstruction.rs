@@ -0,0 +1,48 @@
+use data_strux::disjoint_set::Dsu;
+
+use crate::csr::Csr;
+
+/// Builds the Kruskal reconstruction tree of an undirected weighted graph. Vertices `0..n` are the
+/// leaves, and every edge merging two components in increasing weight order adds a new node
+/// `n, n + 1, ...` whose children are the nodes of the two components. Returns the tree as an
+/// undirected CSR and the weight of every node, with `i64::MIN` for leaves. The minimum over all
+/// paths between two leaves of the maximum edge weight is then the weight of their LCA. A
+/// disconnected graph yields a forest rooted at the last node of each component.
+///
+/// # Complexity
+/// Time: O(n + m log m)
+pub fn kruskal_reconstruction_tree(n: usize, edges: &[(usize, usize, i64)]) -> (Csr<()>, Vec<i64>) {
+    let mut sorted: Vec<(i64, usize, usize)> = edges
+        .iter()
+        .map(|&(u, v, w)| {
+            debug_assert!(
+                u < n && v < n,
+                "vertex out of bounds: u={}, v={}, n={}",
+                u,
+                v,
+                n
+            );
+            (w, u, v)
+        })
+        .collect();
+    sorted.sort_unstable();
+    let mut dsu = Dsu::new(n);
+    // `node[r]` is the tree node of the component whose DSU root is `r`.
+    let mut node: Vec<usize> = (0..n).collect();
+    let mut weight = vec![i64::MIN; n];
+    let mut tree_edges = Vec::with_capacity(2 * n);
+    for (w, u, v) in sorted {
+        let (a, b) = (node[dsu.root(u)], node[dsu.root(v)]);
+        if dsu.unite(u, v) {
+            let x = weight.len();
+            weight.push(w);
+            tree_edges.push((x, a));
+            tree_edges.push((x, b));
+            node[dsu.root(u)] = x;
+        }
+    }
+    (
+        Csr::from_undirected_unweighted(weight.len(), &tree_edges),
+        weight,
+    )
+}
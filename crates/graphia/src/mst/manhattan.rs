@@ -0,0 +1,55 @@
+use data_strux::disjoint_set::Dsu;
+
+/// Returns the total weight and the edges of a minimum spanning tree of points under the
+/// Manhattan (L1) distance. A sweep over each of the four octant pairs keeps only the nearest
+/// neighbor per octant, giving O(n) candidate edges that are then fed to Kruskal's algorithm.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn manhattan_mst(points: &[(i64, i64)]) -> (i64, Vec<(usize, usize)>) {
+    let n = points.len();
+    let mut ps = points.to_vec();
+    let mut id: Vec<usize> = (0..n).collect();
+    let mut candidates = Vec::with_capacity(n << 2);
+    for k in 0..4 {
+        id.sort_unstable_by_key(|&i| ps[i].0 + ps[i].1);
+        // Keyed by `-y`; every entry is a point still waiting for its nearest neighbor in the
+        // octant being swept.
+        let mut sweep: std::collections::BTreeMap<i64, usize> = std::collections::BTreeMap::new();
+        for &i in id.iter() {
+            let (x, y) = ps[i];
+            let mut done = Vec::new();
+            for (&key, &j) in sweep.range(-y..) {
+                let (dx, dy) = (x - ps[j].0, y - ps[j].1);
+                if dy > dx {
+                    break;
+                }
+                candidates.push((dx + dy, i, j));
+                done.push(key);
+            }
+            for key in done {
+                sweep.remove(&key);
+            }
+            sweep.insert(-y, i);
+        }
+        for p in ps.iter_mut() {
+            if k & 1 == 1 {
+                p.0 = -p.0;
+            } else {
+                *p = (p.1, p.0);
+            }
+        }
+    }
+
+    candidates.sort_unstable();
+    let mut dsu = Dsu::new(n);
+    let mut total = 0;
+    let mut edges = Vec::with_capacity(n.saturating_sub(1));
+    for (w, u, v) in candidates {
+        if dsu.unite(u, v) {
+            total += w;
+            edges.push((u, v));
+        }
+    }
+    (total, edges)
+}
@@ -0,0 +1,7 @@
+mod kruskal_reconstruction;
+mod manhattan;
+mod prim;
+
+pub use kruskal_reconstruction::kruskal_reconstruction_tree;
+pub use manhattan::manhattan_mst;
+pub use prim::{prim, prim_dense};
@@ -0,0 +1,73 @@
+use algebrae::linear::Matrix;
+
+use crate::csr::Csr;
+
+/// Returns the total weight of a minimum spanning tree of an undirected graph by Prim's algorithm
+/// with a binary heap. If the graph is disconnected returns `None`.
+///
+/// # Complexity
+/// Time: O((n + m) log m)
+pub fn prim(n: usize, edges: &[(usize, usize, i64)]) -> Option<i64> {
+    debug_assert!(n > 0, "graph must not be empty");
+    let graph = Csr::from_undirected_weighted(n, edges);
+    let mut used = vec![false; n];
+    let mut heap = std::collections::BinaryHeap::new();
+    let mut total = 0;
+    let mut count = 0;
+    heap.push(std::cmp::Reverse((0, 0)));
+    unsafe {
+        let used = used.as_mut_ptr();
+        while let Some(std::cmp::Reverse((w, u))) = heap.pop() {
+            if *used.add(u) {
+                continue;
+            }
+            *used.add(u) = true;
+            total += w;
+            count += 1;
+            for &(v, w) in graph.adj(u) {
+                if !*used.add(v) {
+                    heap.push(std::cmp::Reverse((w, v)));
+                }
+            }
+        }
+    }
+    (count == n).then_some(total)
+}
+
+/// Returns the total weight of a minimum spanning tree of an undirected graph given as a
+/// symmetric adjacency matrix by Prim's algorithm, where `i64::MAX` means no edge. If the graph
+/// is disconnected returns `None`.
+///
+/// # Complexity
+/// Time: O(n^2)
+pub fn prim_dense(adj: &Matrix<i64>) -> Option<i64> {
+    debug_assert!(adj.is_square(), "matrix must be square");
+    let n = adj.h();
+    let mut used = vec![false; n];
+    let mut dist = vec![i64::MAX; n];
+    let mut total = 0;
+    unsafe {
+        let used = used.as_mut_ptr();
+        let dist = dist.as_mut_ptr();
+        *dist = 0;
+        for _ in 0..n {
+            let mut u = !0;
+            for v in 0..n {
+                if !*used.add(v) && (u == !0 || *dist.add(v) < *dist.add(u)) {
+                    u = v;
+                }
+            }
+            if *dist.add(u) == i64::MAX {
+                return None;
+            }
+            *used.add(u) = true;
+            total += *dist.add(u);
+            for (v, &w) in adj[u].iter().enumerate() {
+                if !*used.add(v) && w < *dist.add(v) {
+                    *dist.add(v) = w;
+                }
+            }
+        }
+    }
+    Some(total)
+}
@@ -1,3 +1,7 @@
 mod kosaraju;
+mod tarjan;
+mod two_sat;
 
 pub use kosaraju::kosaraju;
+pub use tarjan::tarjan;
+pub use two_sat::TwoSat;
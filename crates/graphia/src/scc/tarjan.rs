@@ -0,0 +1,73 @@
+use crate::csr::Csr;
+
+/// Computes strongly connected components using Tarjan's algorithm in a single iterative DFS.
+/// Returns a vector `comp` where `comp[v]` is the SCC index of vertex `v`. SCC indices are in
+/// topological order, as with `kosaraju`.
+///
+/// # Complexity
+/// Time: O(n + m)
+pub fn tarjan(graph: &Csr<()>) -> Vec<usize> {
+    let n = graph.num_vertices();
+
+    let mut ord = vec![!0; n];
+    let mut low = vec![0; n];
+    let mut comp = vec![!0; n];
+    let mut visited = Vec::with_capacity(n);
+    // Pairs of a vertex and the index of its next outgoing edge to explore.
+    let mut call = Vec::new();
+    let mut time = 0;
+    let mut num_comp = 0;
+
+    unsafe {
+        let o = ord.as_mut_ptr();
+        let l = low.as_mut_ptr();
+        let c = comp.as_mut_ptr();
+        for s in 0..n {
+            if *o.add(s) != !0 {
+                continue;
+            }
+            *o.add(s) = time;
+            *l.add(s) = time;
+            time += 1;
+            visited.push(s);
+            call.push((s, 0));
+
+            while let Some(&mut (x, ref mut i)) = call.last_mut() {
+                let adj = graph.adj(x);
+                if *i < adj.len() {
+                    let y = adj[*i].0;
+                    *i += 1;
+                    if *o.add(y) == !0 {
+                        *o.add(y) = time;
+                        *l.add(y) = time;
+                        time += 1;
+                        visited.push(y);
+                        call.push((y, 0));
+                    } else if *c.add(y) == !0 {
+                        *l.add(x) = (*l.add(x)).min(*o.add(y));
+                    }
+                    continue;
+                }
+                call.pop();
+                if let Some(&(p, _)) = call.last() {
+                    *l.add(p) = (*l.add(p)).min(*l.add(x));
+                }
+                if *l.add(x) == *o.add(x) {
+                    while let Some(y) = visited.pop() {
+                        *c.add(y) = num_comp;
+                        if y == x {
+                            break;
+                        }
+                    }
+                    num_comp += 1;
+                }
+            }
+        }
+    }
+
+    // Components are found in reverse topological order.
+    for c in comp.iter_mut() {
+        *c = num_comp - 1 - *c;
+    }
+    comp
+}
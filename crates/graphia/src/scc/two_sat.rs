@@ -0,0 +1,175 @@
+use crate::{csr::Csr, scc::tarjan};
+
+/// A 2-SAT solver over `n` boolean variables, building the implication graph over the `2n`
+/// literals and solving it with strongly connected components. A literal is a pair `(x, v)`
+/// standing for `x == v`, and is the vertex `2x + v` of the implication graph, so that its
+/// negation is the vertex `2x + !v`.
+///
+/// # Complexity
+/// Space: O(n + m)
+pub struct TwoSat {
+    n: usize,
+    edges: Vec<(usize, usize)>,
+}
+
+impl TwoSat {
+    /// Creates a new 2-SAT instance with `n` variables and no clauses.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Adds the clause `(x == vx) || (y == vy)`. A unit clause forcing `x == vx` is
+    /// `add_clause(x, vx, x, vx)`.
+    ///
+    /// # Complexity
+    /// Time: O(1) amortized
+    pub fn add_clause(&mut self, x: usize, vx: bool, y: usize, vy: bool) {
+        debug_assert!(
+            x < self.n && y < self.n,
+            "variable out of bounds: x={}, y={}, n={}",
+            x,
+            y,
+            self.n
+        );
+        let (a, b) = (2 * x + vx as usize, 2 * y + vy as usize);
+        self.edges.push((a ^ 1, b));
+        self.edges.push((b ^ 1, a));
+    }
+
+    /// Adds the clause `x || y`.
+    ///
+    /// # Complexity
+    /// Time: O(1) amortized
+    pub fn add_or(&mut self, x: usize, y: usize) {
+        self.add_clause(x, true, y, true);
+    }
+
+    /// Adds the implication `(x == vx) -> (y == vy)`.
+    ///
+    /// # Complexity
+    /// Time: O(1) amortized
+    pub fn add_implication(&mut self, x: usize, vx: bool, y: usize, vy: bool) {
+        self.add_clause(x, !vx, y, vy);
+    }
+
+    /// Forces `x` to be true.
+    ///
+    /// # Complexity
+    /// Time: O(1) amortized
+    pub fn set_true(&mut self, x: usize) {
+        self.add_clause(x, true, x, true);
+    }
+
+    /// Forces `x` to be false.
+    ///
+    /// # Complexity
+    /// Time: O(1) amortized
+    pub fn set_false(&mut self, x: usize) {
+        self.add_clause(x, false, x, false);
+    }
+
+    /// Adds clauses so that at most one of `lits` holds.
+    ///
+    /// # Complexity
+    /// Time: O(k^2), where k = lits.len().
+    pub fn add_at_most_one(&mut self, lits: &[(usize, bool)]) {
+        for (i, &(x, vx)) in lits.iter().enumerate() {
+            for &(y, vy) in lits[i + 1..].iter() {
+                self.add_clause(x, !vx, y, !vy);
+            }
+        }
+    }
+
+    /// Adds clauses so that exactly one of `lits` holds, which must have one or two literals.
+    ///
+    /// Exactly one of k >= 3 literals cannot be expressed in 2-SAT, even with auxiliary variables:
+    /// the solutions of a 2-SAT instance are closed under the bitwise majority of any three of
+    /// them, and so are their projections onto the original variables, but the majority of the
+    /// three assignments setting one of three literals each sets none of them. Such constraints
+    /// panic rather than silently adding a weaker one.
+    ///
+    /// # Complexity
+    /// Time: O(1) amortized
+    pub fn add_exactly_one(&mut self, lits: &[(usize, bool)]) {
+        assert!(
+            matches!(lits.len(), 1 | 2),
+            "exactly one of k literals is only expressible in 2-SAT for k = 1, 2: k={}",
+            lits.len()
+        );
+        let (x, vx) = lits[0];
+        let (y, vy) = lits[lits.len() - 1];
+        self.add_clause(x, vx, y, vy);
+        self.add_at_most_one(lits);
+    }
+
+    /// Returns an assignment satisfying all clauses, or `None` if there is none.
+    ///
+    /// # Complexity
+    /// Time: O(n + m)
+    pub fn solve(&self) -> Option<Vec<bool>> {
+        let comp = tarjan(&Csr::from_directed_unweighted(2 * self.n, &self.edges));
+        // A literal later in topological order than its negation cannot imply it.
+        (0..self.n)
+            .map(|x| match comp[2 * x].cmp(&comp[2 * x + 1]) {
+                std::cmp::Ordering::Equal => None,
+                ord => Some(ord == std::cmp::Ordering::Less),
+            })
+            .collect()
+    }
+
+    /// Returns the number of variables.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exactly_one() {
+        let mut ts = TwoSat::new(2);
+        ts.add_exactly_one(&[(0, true), (1, false)]);
+        ts.set_true(0);
+        assert_eq!(ts.solve(), Some(vec![true, true]));
+
+        let mut ts = TwoSat::new(2);
+        ts.add_exactly_one(&[(0, true), (1, true)]);
+        ts.set_true(0);
+        ts.set_true(1);
+        assert_eq!(ts.solve(), None);
+
+        let mut ts = TwoSat::new(1);
+        ts.add_exactly_one(&[(0, false)]);
+        assert_eq!(ts.solve(), Some(vec![false]));
+    }
+
+    #[test]
+    fn at_most_one_of_three() {
+        let mut ts = TwoSat::new(3);
+        ts.add_at_most_one(&[(0, true), (1, true), (2, true)]);
+        ts.add_or(1, 2);
+        ts.set_false(2);
+        assert_eq!(ts.solve(), Some(vec![false, true, false]));
+        ts.add_implication(1, true, 0, true);
+        assert_eq!(ts.solve(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn exactly_one_of_three() {
+        TwoSat::new(3).add_exactly_one(&[(0, true), (1, true), (2, true)]);
+    }
+}
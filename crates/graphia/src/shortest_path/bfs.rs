@@ -0,0 +1,64 @@
+use std::collections::VecDeque;
+
+use crate::csr::Csr;
+
+/// Returns the number of edges from the nearest of `sources` to every vertex, ignoring edge
+/// weights, or `u32::MAX` for vertices unreachable from all of them. All sources start the BFS
+/// at distance 0, and duplicated sources are allowed.
+///
+/// # Complexity
+/// Time: O(n + m)
+pub fn multi_source_bfs<W: Copy>(graph: &Csr<W>, sources: &[usize]) -> Vec<u32> {
+    let n = graph.num_vertices();
+    let mut dist = vec![u32::MAX; n];
+    let mut queue = Vec::with_capacity(n);
+    for &s in sources {
+        debug_assert!(s < n, "vertex out of bounds: s={}, n={}", s, n);
+        if dist[s] == u32::MAX {
+            dist[s] = 0;
+            queue.push(s);
+        }
+    }
+    let mut head = 0;
+    while head < queue.len() {
+        let u = queue[head];
+        head += 1;
+        for &(v, _) in graph.adj(u) {
+            if dist[v] == u32::MAX {
+                dist[v] = dist[u] + 1;
+                queue.push(v);
+            }
+        }
+    }
+    dist
+}
+
+/// Returns the shortest distance from `src` to every vertex of a graph whose edge weights are all
+/// 0 or 1, or `u32::MAX` for unreachable vertices. Relaxations along 0-weight edges go to the
+/// front of the deque and 1-weight ones to the back.
+///
+/// # Complexity
+/// Time: O(n + m)
+pub fn bfs01(graph: &Csr<u32>, src: usize) -> Vec<u32> {
+    let n = graph.num_vertices();
+    debug_assert!(src < n, "vertex out of bounds: src={}, n={}", src, n);
+    let mut dist = vec![u32::MAX; n];
+    let mut deque = VecDeque::with_capacity(n);
+    dist[src] = 0;
+    deque.push_back(src);
+    while let Some(u) = deque.pop_front() {
+        for &(v, w) in graph.adj(u) {
+            debug_assert!(w <= 1, "edge weight must be 0 or 1: w={}", w);
+            let d = dist[u] + w;
+            if d < dist[v] {
+                dist[v] = d;
+                if w == 0 {
+                    deque.push_front(v);
+                } else {
+                    deque.push_back(v);
+                }
+            }
+        }
+    }
+    dist
+}
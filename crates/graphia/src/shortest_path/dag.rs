@@ -0,0 +1,70 @@
+use crate::{csr::Csr, shortest_path::dijkstra::dijkstra};
+
+/// Returns the shortest-path DAG from `s`, the subgraph of edges `(u, v, w)` with `u` reachable
+/// and `dist[u] + w == dist[v]`. Edges keep their original order within each adjacency list.
+/// With zero-weight edges the result may contain cycles.
+///
+/// # Complexity
+/// Time: O((n + m) log m)
+pub fn shortest_path_dag(graph: &Csr<u64>, s: usize) -> Csr<()> {
+    let n = graph.num_vertices();
+    let (dist, _) = dijkstra(graph, s);
+    let mut edges = Vec::new();
+    for u in 0..n {
+        if let Some(du) = dist[u] {
+            for &(v, w) in graph.adj(u) {
+                if dist[v] == Some(du + w) {
+                    edges.push((u, v));
+                }
+            }
+        }
+    }
+    Csr::from_directed_unweighted(n, &edges)
+}
+
+/// Returns the number of shortest paths from `s` to every vertex modulo `modulus`, where
+/// unreachable vertices have 0. Edge weights must be positive so that the shortest-path DAG is
+/// acyclic.
+///
+/// # Complexity
+/// Time: O((n + m) log m)
+pub fn count_shortest_paths(graph: &Csr<u64>, s: usize, modulus: u64) -> Vec<u64> {
+    debug_assert!(modulus > 0, "modulus must not be zero");
+    let n = graph.num_vertices();
+    debug_assert!(s < n, "source vertex out of bounds: s={}, n={}", s, n);
+    // Counts are accumulated within a single Dijkstra pass: a strict improvement restarts the
+    // count of `v`, and an equal-distance relaxation adds to it. With positive weights every
+    // predecessor of `u` is settled before `u` is popped, so its count is final by then.
+    let mut dist: Vec<Option<u64>> = vec![None; n];
+    let mut count = vec![0; n];
+    let mut heap = std::collections::BinaryHeap::new();
+    dist[s] = Some(0);
+    count[s] = 1 % modulus;
+    heap.push(std::cmp::Reverse((0, s)));
+    unsafe {
+        let dist = dist.as_mut_ptr();
+        let count = count.as_mut_ptr();
+        while let Some(std::cmp::Reverse((d, u))) = heap.pop() {
+            if (*dist.add(u)).is_some_and(|x| x < d) {
+                continue;
+            }
+            let c = *count.add(u);
+            for &(v, w) in graph.adj(u) {
+                let nd = d + w;
+                match *dist.add(v) {
+                    Some(x) if x < nd => {}
+                    Some(x) if x == nd => {
+                        *count.add(v) =
+                            ((*count.add(v) as u128 + c as u128) % modulus as u128) as u64;
+                    }
+                    _ => {
+                        *dist.add(v) = Some(nd);
+                        *count.add(v) = c;
+                        heap.push(std::cmp::Reverse((nd, v)));
+                    }
+                }
+            }
+        }
+    }
+    count
+}
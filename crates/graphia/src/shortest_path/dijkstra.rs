@@ -0,0 +1,56 @@
+use crate::csr::{Csr, EdgeWeight};
+
+/// Computes single-source shortest distances from `src` by Dijkstra's algorithm. Returns the
+/// distance of every vertex (`None` if unreachable) and its predecessor (`!0` if none).
+///
+/// # Complexity
+/// Time: O((n + m) log m)
+pub fn dijkstra<W: EdgeWeight>(graph: &Csr<W>, src: usize) -> (Vec<Option<W::Dist>>, Vec<usize>) {
+    let n = graph.num_vertices();
+    debug_assert!(src < n, "source vertex out of bounds: src={}, n={}", src, n);
+    let mut dist: Vec<Option<W::Dist>> = vec![None; n];
+    let mut prev = vec![!0; n];
+    let mut heap = std::collections::BinaryHeap::new();
+    dist[src] = Some(W::Dist::default());
+    heap.push(std::cmp::Reverse((W::Dist::default(), src)));
+    unsafe {
+        let dist = dist.as_mut_ptr();
+        let prev = prev.as_mut_ptr();
+        while let Some(std::cmp::Reverse((d, u))) = heap.pop() {
+            if (*dist.add(u)).is_some_and(|x| x < d) {
+                continue;
+            }
+            for &(v, w) in graph.adj(u) {
+                let nd = d + w.dist();
+                if (*dist.add(v)).is_none_or(|x| nd < x) {
+                    *dist.add(v) = Some(nd);
+                    *prev.add(v) = u;
+                    heap.push(std::cmp::Reverse((nd, v)));
+                }
+            }
+        }
+    }
+    (dist, prev)
+}
+
+/// Reconstructs the shortest path from the source to `dst` out of the predecessor array returned
+/// by `dijkstra`. `dst` must be reachable from the source.
+///
+/// # Complexity
+/// Time: O(path length)
+pub fn path_to(prev: &[usize], dst: usize) -> Vec<usize> {
+    debug_assert!(
+        dst < prev.len(),
+        "vertex out of bounds: dst={}, n={}",
+        dst,
+        prev.len()
+    );
+    let mut res = vec![dst];
+    let mut v = dst;
+    while prev[v] != !0 {
+        v = prev[v];
+        res.push(v);
+    }
+    res.reverse();
+    res
+}
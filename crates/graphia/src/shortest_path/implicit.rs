@@ -0,0 +1,55 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Computes the shortest distance from `start` to a state satisfying `is_goal` by Dijkstra's
+/// algorithm over a graph given implicitly by `neighbors`, which lists the successors of a state
+/// with their non-negative costs. States are discovered lazily and numbered in a hash map, so
+/// the state space is never built as a whole. Returns `None` if no goal is reachable.
+///
+/// # Complexity
+/// Time: O((n + m) log m) hash operations over the n visited states and m generated edges.
+pub fn dijkstra_implicit<S, D, G, N, I>(start: S, is_goal: G, neighbors: N) -> Option<D>
+where
+    S: Clone + Eq + Hash,
+    D: Copy + Ord + Default + std::ops::Add<Output = D>,
+    G: Fn(&S) -> bool,
+    N: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, D)>,
+{
+    let mut id: HashMap<S, usize> = HashMap::new();
+    let mut states = vec![start.clone()];
+    let mut dist = vec![D::default()];
+    let mut done = vec![false];
+    id.insert(start, 0);
+    let mut heap = std::collections::BinaryHeap::new();
+    heap.push(std::cmp::Reverse((D::default(), 0)));
+    while let Some(std::cmp::Reverse((d, u))) = heap.pop() {
+        if done[u] {
+            continue;
+        }
+        done[u] = true;
+        if is_goal(&states[u]) {
+            return Some(d);
+        }
+        for (s, w) in neighbors(&states[u]) {
+            debug_assert!(w >= D::default(), "costs must be non-negative");
+            let nd = d + w;
+            match id.get(&s) {
+                Some(&v) => {
+                    if !done[v] && nd < dist[v] {
+                        dist[v] = nd;
+                        heap.push(std::cmp::Reverse((nd, v)));
+                    }
+                }
+                None => {
+                    let v = states.len();
+                    id.insert(s.clone(), v);
+                    states.push(s);
+                    dist.push(nd);
+                    done.push(false);
+                    heap.push(std::cmp::Reverse((nd, v)));
+                }
+            }
+        }
+    }
+    None
+}
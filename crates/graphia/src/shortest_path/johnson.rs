@@ -0,0 +1,50 @@
+use crate::{csr::Csr, shortest_path::dijkstra::dijkstra};
+
+/// Computes all-pairs shortest distances on a directed graph which may have negative edges by
+/// Johnson's algorithm. Edges are reweighted by a Bellman-Ford potential, then Dijkstra's algorithm
+/// runs from every vertex. `res[u][v]` is `i64::MAX` when `v` is unreachable from `u`. Returns
+/// `None` if the graph has a negative cycle.
+///
+/// # Complexity
+/// Time: O(nm log m)
+pub fn johnson(n: usize, edges: &[(usize, usize, i64)]) -> Option<Vec<Vec<i64>>> {
+    let mut h = vec![0i64; n];
+    unsafe {
+        let h = h.as_mut_ptr();
+        for iter in 0..=n {
+            let mut updated = false;
+            for &(u, v, w) in edges {
+                debug_assert!(u < n, "source vertex out of bounds: u={}, n={}", u, n);
+                debug_assert!(v < n, "destination vertex out of bounds: v={}, n={}", v, n);
+                if *h.add(u) + w < *h.add(v) {
+                    *h.add(v) = *h.add(u) + w;
+                    updated = true;
+                }
+            }
+            if !updated {
+                break;
+            }
+            if iter == n {
+                return None;
+            }
+        }
+    }
+
+    let reweighted: Vec<(usize, usize, i64)> = edges
+        .iter()
+        .map(|&(u, v, w)| (u, v, w + h[u] - h[v]))
+        .collect();
+    let graph = Csr::from_directed_weighted(n, &reweighted);
+
+    let mut res = Vec::with_capacity(n);
+    for s in 0..n {
+        let (dist, _) = dijkstra(&graph, s);
+        res.push(
+            dist.into_iter()
+                .zip(h.iter())
+                .map(|(d, &ht)| d.map_or(i64::MAX, |d| d - h[s] + ht))
+                .collect(),
+        );
+    }
+    Some(res)
+}
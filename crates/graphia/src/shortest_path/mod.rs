@@ -0,0 +1,11 @@
+mod bfs;
+mod dag;
+mod dijkstra;
+mod implicit;
+mod johnson;
+
+pub use bfs::{bfs01, multi_source_bfs};
+pub use dag::{count_shortest_paths, shortest_path_dag};
+pub use dijkstra::{dijkstra, path_to};
+pub use implicit::dijkstra_implicit;
+pub use johnson::johnson;
@@ -0,0 +1,41 @@
+use algebrae::{linear::Matrix, num_theory::Gf};
+
+/// Returns the sum over all spanning trees of the undirected multigraph on `n` vertices of the
+/// product of their edge weights, by the weighted Matrix-Tree theorem: the determinant of the
+/// weighted Laplacian with the last row and column removed. Self-loops are ignored, and with
+/// unit weights this is the number of spanning trees.
+///
+/// # Complexity
+/// Time: O(n^3 + m)
+pub fn weighted_spanning_tree_count<const P: u32>(
+    n: usize,
+    edges: &[(usize, usize, Gf<P>)],
+) -> Gf<P> {
+    if n <= 1 {
+        return Gf::new(1);
+    }
+    let mut lap = Matrix::zero(n - 1, n - 1);
+    for &(u, v, w) in edges {
+        debug_assert!(
+            u < n && v < n,
+            "vertex out of bounds: u={}, v={}, n={}",
+            u,
+            v,
+            n,
+        );
+        if u == v {
+            continue;
+        }
+        if u < n - 1 {
+            lap[u][u] += w;
+        }
+        if v < n - 1 {
+            lap[v][v] += w;
+        }
+        if u < n - 1 && v < n - 1 {
+            lap[u][v] -= w;
+            lap[v][u] -= w;
+        }
+    }
+    lap.det()
+}
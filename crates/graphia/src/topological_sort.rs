@@ -0,0 +1,32 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::csr::Csr;
+
+/// Returns a topological order of a directed graph by Kahn's algorithm, or `None` if the graph
+/// has a cycle. Among the vertices with indegree 0 the smallest one is taken first, so the result
+/// is the lexicographically smallest topological order.
+///
+/// # Complexity
+/// Time: O(n log n + m)
+pub fn topological_sort<W: Copy>(graph: &Csr<W>) -> Option<Vec<usize>> {
+    let n = graph.num_vertices();
+    let mut indeg = vec![0usize; n];
+    for u in 0..n {
+        for &(v, _) in graph.adj(u) {
+            indeg[v] += 1;
+        }
+    }
+    let mut heap: BinaryHeap<Reverse<usize>> =
+        (0..n).filter(|&v| indeg[v] == 0).map(Reverse).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(Reverse(u)) = heap.pop() {
+        order.push(u);
+        for &(v, _) in graph.adj(u) {
+            indeg[v] -= 1;
+            if indeg[v] == 0 {
+                heap.push(Reverse(v));
+            }
+        }
+    }
+    (order.len() == n).then_some(order)
+}
@@ -0,0 +1,161 @@
+use crate::csr::Csr;
+
+/// A binary lifting data structure answering level ancestor and path jump queries.
+///
+/// # Complexity
+/// Space: O(n log n)
+pub struct BinaryLifting {
+    depth: Box<[u32]>,
+    // `up[k * n + v]` is the 2^k-th ancestor of `v`, or the root if it does not exist.
+    up: Box<[u32]>,
+}
+
+impl BinaryLifting {
+    /// Creates a new binary lifting from CSR.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn from_csr<W: Copy>(root: usize, tree: &Csr<W>) -> Self {
+        let n = tree.num_vertices();
+        debug_assert!(n > 0, "n must not be zero");
+        debug_assert!(root < n, "root is out of bounds: root={}, n={}", root, n);
+        let log = (usize::BITS - n.leading_zeros()) as usize;
+        let mut depth = vec![0u32; n];
+        let mut up = vec![root as u32; n * log];
+        let mut seen = vec![false; n];
+        let mut stack = vec![root];
+        seen[root] = true;
+        while let Some(u) = stack.pop() {
+            for &(v, _) in tree.adj(u) {
+                if !seen[v] {
+                    seen[v] = true;
+                    depth[v] = depth[u] + 1;
+                    up[v] = u as u32;
+                    stack.push(v);
+                }
+            }
+        }
+        for k in 1..log {
+            for v in 0..n {
+                up[k * n + v] = up[(k - 1) * n + up[(k - 1) * n + v] as usize];
+            }
+        }
+        Self {
+            depth: depth.into_boxed_slice(),
+            up: up.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the ancestor of `v` that is `k` levels up, or `None` if `k` exceeds the depth of
+    /// `v`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn kth_ancestor(&self, v: usize, k: usize) -> Option<usize> {
+        debug_assert!(
+            v < self.len(),
+            "v is out of bounds: v={}, n={}",
+            v,
+            self.len()
+        );
+        if k > self.depth(v) {
+            return None;
+        }
+        let n = self.len();
+        let mut v = v;
+        let mut k = k;
+        while k > 0 {
+            let i = k.trailing_zeros() as usize;
+            v = self.up[i * n + v] as usize;
+            k &= k - 1;
+        }
+        Some(v)
+    }
+
+    /// Returns LCA of `u` and `v`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        debug_assert!(
+            u < self.len(),
+            "u is out of bounds: u={}, n={}",
+            u,
+            self.len()
+        );
+        debug_assert!(
+            v < self.len(),
+            "v is out of bounds: v={}, n={}",
+            v,
+            self.len()
+        );
+        let (mut u, mut v) = if self.depth(u) >= self.depth(v) {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        u = self.kth_ancestor(u, self.depth(u) - self.depth(v)).unwrap();
+        if u == v {
+            return u;
+        }
+        let n = self.len();
+        for k in (0..self.up.len() / n).rev() {
+            let (a, b) = (self.up[k * n + u], self.up[k * n + v]);
+            if a != b {
+                u = a as usize;
+                v = b as usize;
+            }
+        }
+        self.up[u] as usize
+    }
+
+    /// Returns the `k`-th vertex on the path from `u` to `v`, where the 0-th is `u`, or `None` if
+    /// `k` exceeds the distance between them.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn jump(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let w = self.lca(u, v);
+        let (du, dv) = (self.depth(u) - self.depth(w), self.depth(v) - self.depth(w));
+        if k <= du {
+            self.kth_ancestor(u, k)
+        } else if k <= du + dv {
+            self.kth_ancestor(v, du + dv - k)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the depth of vertex `v`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn depth(&self, v: usize) -> usize {
+        debug_assert!(
+            v < self.len(),
+            "v is out of bounds: v={}, n={}",
+            v,
+            self.len()
+        );
+        self.depth[v] as usize
+    }
+
+    /// Returns distance between `u` and `v`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        self.depth(u) + self.depth(v) - 2 * self.depth(self.lca(u, v))
+    }
+
+    /// Returns the number of vertices in tree.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[allow(clippy::len_without_is_empty)]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.depth.len()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    csr::{Csr, EdgeWeight},
+    tree::diameter_path,
+};
+
+/// Returns the centers of a tree, the vertices minimizing the eccentricity (the distance to the
+/// farthest vertex), in increasing order. Every center lies on a diameter path, so this walks the
+/// path of `diameter_path` and keeps the vertices closest to its weighted midpoint. With positive
+/// edge weights there are one or two centers, and two centers are adjacent.
+///
+/// # Complexity
+/// Time: O(n)
+pub fn tree_center<W: EdgeWeight>(tree: &Csr<W>) -> Vec<usize> {
+    let (path, ecc) = path_eccentricities(tree);
+    let radius = *ecc.iter().min().unwrap();
+    let mut res: Vec<usize> = path
+        .into_iter()
+        .zip(ecc)
+        .filter_map(|(v, e)| (e == radius).then_some(v))
+        .collect();
+    res.sort_unstable();
+    res
+}
+
+/// Returns the radius of a tree, the minimum eccentricity over all vertices.
+///
+/// # Complexity
+/// Time: O(n)
+pub fn tree_radius<W: EdgeWeight>(tree: &Csr<W>) -> W::Dist {
+    let (_, ecc) = path_eccentricities(tree);
+    ecc.into_iter().min().unwrap()
+}
+
+/// Returns a diameter path and the eccentricity of each vertex on it, which is the larger of its
+/// distances to the two endpoints.
+fn path_eccentricities<W: EdgeWeight>(tree: &Csr<W>) -> (Vec<usize>, Vec<W::Dist>) {
+    let (_, path) = diameter_path(tree);
+    let k = path.len();
+    let weight = |u: usize, v: usize| {
+        tree.adj(u)
+            .iter()
+            .find(|&&(x, _)| x == v)
+            .map(|&(_, w)| w.dist())
+            .unwrap()
+    };
+    let mut front = vec![W::Dist::default(); k];
+    let mut back = vec![W::Dist::default(); k];
+    for i in 1..k {
+        front[i] = front[i - 1] + weight(path[i - 1], path[i]);
+    }
+    for i in (0..k - 1).rev() {
+        back[i] = back[i + 1] + weight(path[i], path[i + 1]);
+    }
+    let ecc = front.into_iter().zip(back).map(|(a, b)| a.max(b)).collect();
+    (path, ecc)
+}
@@ -0,0 +1,68 @@
+use crate::csr::Csr;
+
+/// Computes the centroid decomposition of a tree. Returns `(parent, root)`, where `parent[v]` is
+/// the parent of `v` in the centroid tree, or `!0` for the root centroid `root`. Every path in the
+/// centroid tree has O(log n) vertices. Components are processed with an explicit stack, so deep
+/// trees do not overflow the call stack.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn centroid_decomposition<W: Copy>(tree: &Csr<W>) -> (Vec<usize>, usize) {
+    let n = tree.num_vertices();
+    debug_assert!(n > 0, "tree must not be empty tree");
+    let mut parent = vec![!0; n];
+    let mut removed = vec![false; n];
+    let mut size = vec![0; n];
+    let mut bfs_parent = vec![!0; n];
+    let mut order = Vec::with_capacity(n);
+    let mut root = !0;
+    // Pairs of a vertex of an undecomposed component and the centroid above that component.
+    let mut stack = vec![(0, !0)];
+    while let Some((s, cp)) = stack.pop() {
+        order.clear();
+        order.push(s);
+        bfs_parent[s] = !0;
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            for &(v, _) in tree.adj(u) {
+                if v != bfs_parent[u] && !removed[v] {
+                    bfs_parent[v] = u;
+                    order.push(v);
+                }
+            }
+        }
+        for &u in order.iter().rev() {
+            size[u] = 1;
+            for &(v, _) in tree.adj(u) {
+                if v != bfs_parent[u] && !removed[v] {
+                    size[u] += size[v];
+                }
+            }
+        }
+        // Walks from `s` towards the heavy child while it holds more than half of the component.
+        let total = order.len();
+        let mut c = s;
+        'walk: loop {
+            for &(v, _) in tree.adj(c) {
+                if v != bfs_parent[c] && !removed[v] && 2 * size[v] > total {
+                    c = v;
+                    continue 'walk;
+                }
+            }
+            break;
+        }
+        removed[c] = true;
+        parent[c] = cp;
+        if cp == !0 {
+            root = c;
+        }
+        for &(v, _) in tree.adj(c) {
+            if !removed[v] {
+                stack.push((v, c));
+            }
+        }
+    }
+    (parent, root)
+}
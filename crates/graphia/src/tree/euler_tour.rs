@@ -133,6 +133,23 @@ impl EulerTour {
         order.into_boxed_slice()
     }
 
+    /// Reorders per-vertex `values` into discovery order, so that `res[tin(i)] = values[i]` and
+    /// the fold of `res` over `subtree(i)` aggregates the subtree of `i`. The result can be fed
+    /// directly into a segment tree or Fenwick tree.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    pub fn remap<S: Clone>(&self, values: &[S]) -> Vec<S> {
+        debug_assert_eq!(
+            values.len(),
+            self.len(),
+            "length mismatch: values={}, n={}",
+            values.len(),
+            self.len()
+        );
+        self.order().iter().map(|&v| values[v].clone()).collect()
+    }
+
     /// Returns the number of vertices in tree.
     ///
     /// # Complexity
@@ -0,0 +1,120 @@
+use crate::{components::connected_components, csr::Csr, tree::Lca};
+
+/// A Lowest Common Ancestor data structure over a forest. The roots of all trees are attached to
+/// a virtual super root, so a query meeting there lies across two trees.
+///
+/// # Complexity
+/// Space: O(n)
+pub struct ForestLca {
+    lca: Lca,
+    root: Box<[u32]>,
+}
+
+impl ForestLca {
+    /// Creates a new forest LCA from CSR, rooting every tree at its smallest vertex.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn from_csr<W: Copy>(forest: &Csr<W>) -> Self {
+        let (count, comp) = connected_components(forest);
+        let mut roots = vec![!0; count];
+        for (v, &c) in comp.iter().enumerate().rev() {
+            roots[c] = v;
+        }
+        Self::with_roots(&roots, forest)
+    }
+
+    /// Creates a new forest LCA from CSR with exactly one root given per tree.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn with_roots<W: Copy>(roots: &[usize], forest: &Csr<W>) -> Self {
+        let n = forest.num_vertices();
+        debug_assert!(n > 0, "n mut not be zero");
+        let mut edges = Vec::with_capacity(forest.num_edges() + (roots.len() << 1));
+        for u in 0..n {
+            for &(v, _) in forest.adj(u) {
+                edges.push((u, v));
+            }
+        }
+        for &r in roots.iter() {
+            debug_assert!(r < n, "root is out of bounds: root={}, n={}", r, n);
+            edges.push((n, r));
+            edges.push((r, n));
+        }
+        let lca = Lca::from_csr(n, &Csr::from_directed_unweighted(n + 1, &edges));
+
+        let mut root = vec![!0u32; n];
+        let mut stack = Vec::new();
+        for &r in roots.iter() {
+            debug_assert!(root[r] == !0, "more than one root in a tree: root={}", r);
+            root[r] = r as u32;
+            stack.push(r);
+            while let Some(u) = stack.pop() {
+                for &(v, _) in forest.adj(u) {
+                    if root[v] == !0 {
+                        root[v] = r as u32;
+                        stack.push(v);
+                    }
+                }
+            }
+        }
+        debug_assert!(root.iter().all(|&r| r != !0), "every tree must have a root");
+        Self {
+            lca,
+            root: root.into_boxed_slice(),
+        }
+    }
+
+    /// Returns LCA of `i` and `j`, or `None` if they are in different trees.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn lca(&self, i: usize, j: usize) -> Option<usize> {
+        let a = self.lca.lca(i, j);
+        (a != self.len()).then_some(a)
+    }
+
+    /// Returns the root of the tree containing vertex `i`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn root(&self, i: usize) -> usize {
+        debug_assert!(
+            i < self.len(),
+            "i is out of bounds: i={}, n={}",
+            i,
+            self.len()
+        );
+        self.root[i] as usize
+    }
+
+    /// Returns the depth of vertex `i` in its tree.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn depth(&self, i: usize) -> usize {
+        self.lca.depth(i) - 1
+    }
+
+    /// Returns distance between `i` and `j`, or `None` if they are in different trees.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn dist(&self, i: usize, j: usize) -> Option<usize> {
+        self.lca(i, j)
+            .map(|a| self.depth(i) + self.depth(j) - 2 * self.depth(a))
+    }
+
+    /// Returns the number of vertices in forest.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[allow(clippy::len_without_is_empty)]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.root.len()
+    }
+}
@@ -288,6 +288,19 @@ impl Hpd {
         }
     }
 
+    /// Returns the vertex intervals `(l, r, forward)` of positions `[l, r)` covering the path from
+    /// `u` to `v`, ordered along the path as in `path_vertex`. When `forward` is `false` the
+    /// interval is traversed from `r - 1` down to `l`, which matters for non-commutative folds.
+    /// Use `path_edge` for edge-indexed queries.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn path(&self, u: usize, v: usize) -> Vec<(usize, usize, bool)> {
+        let mut res = Vec::new();
+        self.path_vertex(u, v, |l, r, forward| res.push((l, r, forward)));
+        res
+    }
+
     /// Decomposes the path from `u` to `v` into O(log n) edge intervals.
     /// Calls `f(l, r, forward)` for each interval `[l, r)` in decomposition order, excluding
     /// the LCA vertex. Edges are identified with their child endpoint.
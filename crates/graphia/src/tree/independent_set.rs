@@ -0,0 +1,47 @@
+use crate::csr::Csr;
+
+/// Returns the maximum total weight of an independent set of a tree, where the empty set has
+/// weight 0.
+///
+/// # Complexity
+/// Time: O(n)
+pub fn max_weight_independent_set<W: Copy>(tree: &Csr<W>, weights: &[i64]) -> i64 {
+    let n = tree.num_vertices();
+    debug_assert!(n > 0, "tree must not be empty tree");
+    debug_assert_eq!(
+        n,
+        weights.len(),
+        "length mismatch: n={}, weights={}",
+        n,
+        weights.len()
+    );
+
+    let mut parent = vec![!0; n];
+    let mut order = Vec::with_capacity(n);
+    // `take[v]` and `skip[v]` are the best weights in the subtree of `v` with and without `v`.
+    let mut take = weights.to_vec();
+    let mut skip = vec![0; n];
+    unsafe {
+        let p = parent.as_mut_ptr();
+        let t = take.as_mut_ptr();
+        let s = skip.as_mut_ptr();
+        order.push(0);
+        let mut i = 0;
+        while i < order.len() {
+            let u = *order.get_unchecked(i);
+            i += 1;
+            for &(v, _) in tree.adj(u) {
+                if v != *p.add(u) {
+                    *p.add(v) = u;
+                    order.push(v);
+                }
+            }
+        }
+        for &v in order.iter().skip(1).rev() {
+            let u = *p.add(v);
+            *t.add(u) += *s.add(v);
+            *s.add(u) += (*t.add(v)).max(*s.add(v));
+        }
+        (*t).max(*s)
+    }
+}
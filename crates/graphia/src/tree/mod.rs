@@ -1,9 +1,25 @@
+mod binary_lifting;
+mod center;
+mod centroid_decomposition;
 mod diameter;
 mod euler_tour;
+mod forest_lca;
 mod hpd;
+mod independent_set;
 mod lca;
+mod path_agg;
+mod reroot;
+mod rooted;
 
+pub use binary_lifting::BinaryLifting;
+pub use center::{tree_center, tree_radius};
+pub use centroid_decomposition::centroid_decomposition;
 pub use diameter::{diameter, diameter_path};
 pub use euler_tour::EulerTour;
+pub use forest_lca::ForestLca;
 pub use hpd::Hpd;
+pub use independent_set::max_weight_independent_set;
 pub use lca::Lca;
+pub use path_agg::TreePathAgg;
+pub use reroot::reroot;
+pub use rooted::rooted_tree;
@@ -0,0 +1,120 @@
+use algebrae::algebra::Monoid;
+
+use crate::csr::Csr;
+
+/// A binary lifting data structure folding the edge weights along tree paths, such as the
+/// bottleneck edge with a max or min monoid. The monoid must be commutative, since the two halves
+/// of a path are folded towards the LCA.
+///
+/// # Complexity
+/// Space: O(n log n)
+pub struct TreePathAgg<S: Monoid> {
+    depth: Box<[u32]>,
+    // `up[k * n + v]` is the 2^k-th ancestor of `v`, or the root if it does not exist, and
+    // `agg[k * n + v]` the fold of the edges on the way there.
+    up: Box<[u32]>,
+    agg: Box<[S]>,
+}
+
+impl<S: Monoid> TreePathAgg<S> {
+    /// Creates a new path aggregation from a CSR whose edge weights are monoid elements.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn from_csr(root: usize, tree: &Csr<S>) -> Self
+    where
+        S: Copy,
+    {
+        let n = tree.num_vertices();
+        debug_assert!(n > 0, "n must not be zero");
+        debug_assert!(root < n, "root is out of bounds: root={}, n={}", root, n);
+        let log = (usize::BITS - n.leading_zeros()) as usize;
+        let mut depth = vec![0u32; n];
+        let mut up = vec![root as u32; n * log];
+        let mut agg = vec![S::id(); n * log];
+        let mut seen = vec![false; n];
+        let mut stack = vec![root];
+        seen[root] = true;
+        while let Some(u) = stack.pop() {
+            for &(v, w) in tree.adj(u) {
+                if !seen[v] {
+                    seen[v] = true;
+                    depth[v] = depth[u] + 1;
+                    up[v] = u as u32;
+                    agg[v] = w;
+                    stack.push(v);
+                }
+            }
+        }
+        for k in 1..log {
+            for v in 0..n {
+                let mid = up[(k - 1) * n + v] as usize;
+                up[k * n + v] = up[(k - 1) * n + mid];
+                agg[k * n + v] = agg[(k - 1) * n + v].op(&agg[(k - 1) * n + mid]);
+            }
+        }
+        Self {
+            depth: depth.into_boxed_slice(),
+            up: up.into_boxed_slice(),
+            agg: agg.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the fold of the edge weights on the path between `u` and `v`, which is `S::id()`
+    /// when `u == v`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn path_fold(&self, u: usize, v: usize) -> S {
+        self.lca_fold(u, v).1
+    }
+
+    /// Returns LCA of `u` and `v`.
+    ///
+    /// # Complexity
+    /// Time: O(log n)
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        self.lca_fold(u, v).0
+    }
+
+    /// Returns the number of vertices in tree.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[allow(clippy::len_without_is_empty)]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.depth.len()
+    }
+
+    fn lca_fold(&self, u: usize, v: usize) -> (usize, S) {
+        let n = self.len();
+        debug_assert!(u < n, "u is out of bounds: u={}, n={}", u, n);
+        debug_assert!(v < n, "v is out of bounds: v={}, n={}", v, n);
+        let (mut u, mut v) = if self.depth[u] >= self.depth[v] {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        let mut res = S::id();
+        let mut d = (self.depth[u] - self.depth[v]) as usize;
+        while d > 0 {
+            let k = d.trailing_zeros() as usize;
+            res = res.op(&self.agg[k * n + u]);
+            u = self.up[k * n + u] as usize;
+            d &= d - 1;
+        }
+        if u == v {
+            return (u, res);
+        }
+        for k in (0..self.up.len() / n).rev() {
+            if self.up[k * n + u] != self.up[k * n + v] {
+                res = res.op(&self.agg[k * n + u]).op(&self.agg[k * n + v]);
+                u = self.up[k * n + u] as usize;
+                v = self.up[k * n + v] as usize;
+            }
+        }
+        res = res.op(&self.agg[u]).op(&self.agg[v]);
+        (self.up[u] as usize, res)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::csr::Csr;
+
+/// Computes a tree DP for every choice of root by rerooting, returning `res[v]` as the value of
+/// the whole tree rooted at `v`. The value of a subtree rooted at `v` is
+/// `add_root(merge(add_edge(dp[c], c, w), ...), v)` over its children `c` joined by weight `w`,
+/// starting from `identity`. `merge` must be associative and commutative with `identity` as its
+/// identity element.
+///
+/// # Complexity
+/// Time: O(n) calls to each closure
+pub fn reroot<W, V, M, E, R>(
+    tree: &Csr<W>,
+    identity: V,
+    merge: M,
+    add_edge: E,
+    add_root: R,
+) -> Vec<V>
+where
+    W: Copy,
+    V: Clone,
+    M: Fn(&V, &V) -> V,
+    E: Fn(&V, usize, W) -> V,
+    R: Fn(&V, usize) -> V,
+{
+    let n = tree.num_vertices();
+    debug_assert!(n > 0, "tree must not be empty tree");
+
+    let mut parent = vec![!0; n];
+    let mut order = Vec::with_capacity(n);
+    order.push(0);
+    let mut i = 0;
+    while i < order.len() {
+        let u = order[i];
+        i += 1;
+        for &(v, _) in tree.adj(u) {
+            if v != parent[u] {
+                parent[v] = u;
+                order.push(v);
+            }
+        }
+    }
+
+    // `down[v]` is the value of the subtree of `v` rooted at 0.
+    let mut acc = vec![identity.clone(); n];
+    let mut down = vec![identity.clone(); n];
+    for &v in order.iter().rev() {
+        down[v] = add_root(&acc[v], v);
+        let p = parent[v];
+        if p != !0 {
+            let w = tree.adj(v).iter().find(|&&(u, _)| u == p).unwrap().1;
+            acc[p] = merge(&acc[p], &add_edge(&down[v], v, w));
+        }
+    }
+
+    // `up[v]` is the value of the component of `parent[v]` after removing `v`, rooted there.
+    let mut up = vec![identity.clone(); n];
+    let mut res = vec![identity.clone(); n];
+    let mut contrib = Vec::new();
+    let mut suffix = Vec::new();
+    for &u in order.iter() {
+        let adj = tree.adj(u);
+        contrib.clear();
+        contrib.extend(adj.iter().map(|&(v, w)| {
+            if v == parent[u] {
+                add_edge(&up[u], v, w)
+            } else {
+                add_edge(&down[v], v, w)
+            }
+        }));
+        suffix.clear();
+        suffix.resize(adj.len() + 1, identity.clone());
+        for k in (0..adj.len()).rev() {
+            suffix[k] = merge(&contrib[k], &suffix[k + 1]);
+        }
+        res[u] = add_root(&suffix[0], u);
+        let mut prefix = identity.clone();
+        for (k, &(v, _)) in adj.iter().enumerate() {
+            if v != parent[u] {
+                up[v] = add_root(&merge(&prefix, &suffix[k + 1]), u);
+            }
+            prefix = merge(&prefix, &contrib[k]);
+        }
+    }
+    res
+}
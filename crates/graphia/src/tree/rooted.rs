@@ -0,0 +1,33 @@
+use crate::csr::Csr;
+
+/// Roots a tree at `root` by BFS. Returns `(parent, order, children)` where `parent[root] = !0`,
+/// `order` lists the vertices in BFS order (so every parent precedes its children), and `children`
+/// is a directed CSR holding only the edges from each parent to its children, with their weights.
+///
+/// # Complexity
+/// Time: O(n)
+pub fn rooted_tree<W: Copy>(root: usize, tree: &Csr<W>) -> (Vec<usize>, Vec<usize>, Csr<W>) {
+    let n = tree.num_vertices();
+    debug_assert!(root < n, "root is out of bounds: root={}, n={}", root, n);
+    let mut parent = vec![!0; n];
+    let mut order = Vec::with_capacity(n);
+    let mut edges = Vec::with_capacity(n - 1);
+    let mut seen = vec![false; n];
+    seen[root] = true;
+    order.push(root);
+    let mut head = 0;
+    while head < order.len() {
+        let u = order[head];
+        head += 1;
+        for &(v, w) in tree.adj(u) {
+            if !seen[v] {
+                seen[v] = true;
+                parent[v] = u;
+                order.push(v);
+                edges.push((u, v, w));
+            }
+        }
+    }
+    debug_assert_eq!(order.len(), n, "tree must be connected");
+    (parent, order, Csr::from_directed_weighted(n, &edges))
+}
@@ -0,0 +1,124 @@
+/// A palindromic tree (eertree) built online over symbols in `0..A`. Node 0 is the imaginary root
+/// of length -1, node 1 is the empty palindrome, and every other node is a distinct palindromic
+/// substring.
+///
+/// # Complexity
+/// Space: O(nA)
+pub struct Eertree<const A: usize> {
+    next: Vec<[u32; A]>,
+    link: Vec<u32>,
+    len: Vec<i32>,
+    text: Vec<usize>,
+    // The node of the longest palindromic suffix ending at each position.
+    suffix: Vec<u32>,
+}
+
+impl<const A: usize> Default for Eertree<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const A: usize> Eertree<A> {
+    /// Creates an empty eertree.
+    ///
+    /// # Complexity
+    /// Time: O(A)
+    pub fn new() -> Self {
+        Self {
+            next: vec![[!0; A]; 2],
+            link: vec![0, 0],
+            len: vec![-1, 0],
+            text: Vec::new(),
+            suffix: Vec::new(),
+        }
+    }
+
+    /// Appends `c` to the text and returns whether a new distinct palindrome appeared.
+    ///
+    /// # Complexity
+    /// Time: Amortized O(1), O(A) when a node is created.
+    pub fn extend(&mut self, c: usize) -> bool {
+        debug_assert!(c < A, "symbol out of bounds: c={}, A={}", c, A);
+        let pos = self.text.len();
+        self.text.push(c);
+        let last = self.suffix.last().map_or(1, |&v| v as usize);
+        let cur = self.find(last, pos);
+        let v = self.next[cur][c];
+        if v != !0 {
+            self.suffix.push(v);
+            return false;
+        }
+
+        let id = self.len.len() as u32;
+        let len = self.len[cur] + 2;
+        let link = if len == 1 {
+            1
+        } else {
+            let w = self.find(self.link[cur] as usize, pos);
+            self.next[w][c]
+        };
+        self.next.push([!0; A]);
+        self.link.push(link);
+        self.len.push(len);
+        self.next[cur][c] = id;
+        self.suffix.push(id);
+        true
+    }
+
+    /// Returns the deepest node on the suffix link chain from `v` whose palindrome can be
+    /// wrapped by `text[pos]` on both sides.
+    fn find(&self, mut v: usize, pos: usize) -> usize {
+        loop {
+            let l = self.len[v];
+            if l == -1 {
+                return v;
+            }
+            let l = l as usize;
+            if pos > l && self.text[pos - 1 - l] == self.text[pos] {
+                return v;
+            }
+            v = self.link[v] as usize;
+        }
+    }
+
+    /// Returns the number of distinct non-empty palindromic substrings of the text.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn num_distinct(&self) -> usize {
+        self.len.len() - 2
+    }
+
+    /// Returns the length of the longest palindromic suffix of `text[..=i]`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn longest_suffix(&self, i: usize) -> usize {
+        debug_assert!(
+            i < self.len(),
+            "index out of bounds: i={}, len={}",
+            i,
+            self.len()
+        );
+        self.len[self.suffix[i] as usize] as usize
+    }
+
+    /// Returns the length of the text.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns whether the text is empty.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
@@ -0,0 +1,49 @@
+use algebrae::algebra::AbelianGroup;
+use data_strux::fenwick_tree::FenwickTree;
+
+/// Returns the number of pairs `i < j` with `a[i] > a[j]`, using a Fenwick tree over
+/// coordinate-compressed values.
+///
+/// # Complexity
+/// Time: O(n log n), Space: O(n)
+pub fn count_inversions<T: Ord>(a: &[T]) -> u64 {
+    let n = a.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_unstable_by(|&i, &j| a[i].cmp(&a[j]));
+    let mut rank = vec![0; n];
+    unsafe {
+        let rank = rank.as_mut_ptr();
+        let mut r = 0;
+        for k in 0..n {
+            if k > 0 && a[order[k - 1]] != a[order[k]] {
+                r += 1;
+            }
+            *rank.add(order[k]) = r;
+        }
+    }
+
+    let mut fenwick = FenwickTree::<Count>::new(n);
+    let mut res = 0;
+    for (seen, &r) in rank.iter().enumerate() {
+        res += seen as u64 - fenwick.prefix_fold(r + 1).0;
+        fenwick.operate(r, Count(1));
+    }
+    res
+}
+
+#[derive(Clone)]
+struct Count(u64);
+
+impl AbelianGroup for Count {
+    fn id() -> Self {
+        Count(0)
+    }
+
+    fn op(&self, rhs: &Self) -> Self {
+        Count(self.0.wrapping_add(rhs.0))
+    }
+
+    fn inv(&self) -> Self {
+        Count(self.0.wrapping_neg())
+    }
+}
@@ -0,0 +1,73 @@
+use algebrae::algebra::Band;
+use data_strux::sparse_table::SparseTable;
+
+use crate::suffix_array::{lcp_array, suffix_array};
+
+#[derive(Clone, Copy)]
+struct Min(usize);
+
+impl Band for Min {
+    #[inline(always)]
+    fn op(&self, other: &Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+}
+
+/// A longest common extension structure answering the length of the longest common prefix of two
+/// suffixes, built from the suffix array and a sparse table over its LCP array.
+///
+/// # Complexity
+/// Space: O(n log n)
+pub struct Lce {
+    rank: Vec<usize>,
+    table: Option<SparseTable<Min>>,
+}
+
+impl Lce {
+    /// Creates a new LCE structure of `s`.
+    ///
+    /// # Complexity
+    /// Time: O(n log n)
+    pub fn from_slice<T: Ord>(s: &[T]) -> Self {
+        let n = s.len();
+        let sa = suffix_array(s);
+        let mut rank = vec![0; n];
+        for (r, &i) in sa.iter().enumerate() {
+            rank[i] = r;
+        }
+        let table = (n > 1)
+            .then(|| SparseTable::from_vec(lcp_array(s, &sa).into_iter().map(Min).collect()));
+        Self { rank, table }
+    }
+
+    /// Returns the length of the longest common prefix of the suffixes starting at `i` and `j`.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    pub fn lce(&self, i: usize, j: usize) -> usize {
+        let n = self.len();
+        debug_assert!(
+            i < n && j < n,
+            "index out of bounds: i={}, j={}, len={}",
+            i,
+            j,
+            n
+        );
+        if i == j {
+            return n - i;
+        }
+        let (a, b) = (self.rank[i], self.rank[j]);
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        self.table.as_ref().unwrap().range_fold(a..b).0
+    }
+
+    /// Returns the length of the sequence.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.rank.len()
+    }
+}
@@ -1,2 +1,8 @@
 pub mod dasg;
+pub mod eertree;
+pub mod inversion;
+pub mod lce;
 pub mod lis;
+pub mod min_rotation;
+pub mod suffix_array;
+pub mod suffix_automaton;
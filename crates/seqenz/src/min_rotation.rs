@@ -0,0 +1,38 @@
+/// Returns the starting index of the lexicographically smallest rotation of `s` by Booth's
+/// algorithm. Among equal rotations the smallest index is returned, so an empty or all-equal
+/// input gives 0.
+///
+/// # Complexity
+/// Time: O(n), Space: O(n)
+pub fn min_rotation<T: Ord>(s: &[T]) -> usize {
+    let n = s.len();
+    if n == 0 {
+        return 0;
+    }
+    // `fail` is the failure function of the rotation starting at `k`, where `!0` means none.
+    let mut fail = vec![!0usize; n << 1];
+    let mut k = 0;
+    unsafe {
+        let f = fail.as_mut_ptr();
+        let at = |i: usize| s.get_unchecked(i % n);
+        for j in 1..n << 1 {
+            let c = at(j);
+            let mut i = *f.add(j - k - 1);
+            while i != !0 && c != at(k + i + 1) {
+                if c < at(k + i + 1) {
+                    k = j - i - 1;
+                }
+                i = *f.add(i);
+            }
+            if i == !0 && c != at(k) {
+                if c < at(k) {
+                    k = j;
+                }
+                *f.add(j - k) = !0;
+            } else {
+                *f.add(j - k) = i.wrapping_add(1);
+            }
+        }
+    }
+    k
+}
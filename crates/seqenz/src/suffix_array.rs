@@ -0,0 +1,161 @@
+/// Computes the suffix array of `s` by prefix doubling with radix sort.
+///
+/// # Complexity
+/// Time: O(n log n), Space: O(n)
+pub fn suffix_array<T: Ord>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut sa: Vec<usize> = (0..n).collect();
+    sa.sort_unstable_by(|&i, &j| s[i].cmp(&s[j]));
+    let mut rank = vec![0; n];
+    let mut tmp = vec![0; n];
+    let mut cnt = vec![0; n + 1];
+    unsafe {
+        let sa = sa.as_mut_ptr();
+        let rank = rank.as_mut_ptr();
+        let tmp = tmp.as_mut_ptr();
+        let cnt = cnt.as_mut_ptr();
+        let mut classes = 1;
+        for i in 1..n {
+            if s[*sa.add(i - 1)] != s[*sa.add(i)] {
+                classes += 1;
+            }
+            *rank.add(*sa.add(i)) = classes - 1;
+        }
+
+        let mut k = 1;
+        while classes < n && k < n {
+            let mut p = 0;
+            for i in n - k..n {
+                *tmp.add(p) = i;
+                p += 1;
+            }
+            for j in 0..n {
+                let i = *sa.add(j);
+                if i >= k {
+                    *tmp.add(p) = i - k;
+                    p += 1;
+                }
+            }
+
+            std::ptr::write_bytes(cnt, 0, classes + 1);
+            for i in 0..n {
+                *cnt.add(*rank.add(i) + 1) += 1;
+            }
+            for c in 0..classes {
+                *cnt.add(c + 1) += *cnt.add(c);
+            }
+            for j in 0..n {
+                let i = *tmp.add(j);
+                let r = *rank.add(i);
+                *sa.add(*cnt.add(r)) = i;
+                *cnt.add(r) += 1;
+            }
+
+            let key = |i: usize| (*rank.add(i), if i + k < n { *rank.add(i + k) } else { !0 });
+            *tmp.add(*sa) = 0;
+            classes = 1;
+            for j in 1..n {
+                if key(*sa.add(j - 1)) != key(*sa.add(j)) {
+                    classes += 1;
+                }
+                *tmp.add(*sa.add(j)) = classes - 1;
+            }
+            std::ptr::copy_nonoverlapping(tmp, rank, n);
+            k <<= 1;
+        }
+    }
+    sa
+}
+
+/// Computes the LCP array by Kasai's algorithm, where `lcp[i]` is the length of the longest common
+/// prefix of the suffixes starting at `sa[i]` and `sa[i + 1]`.
+///
+/// # Complexity
+/// Time: O(n), Space: O(n)
+pub fn lcp_array<T: Eq>(s: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    debug_assert_eq!(n, sa.len(), "length mismatch: s={}, sa={}", n, sa.len());
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut rank = vec![0; n];
+    let mut lcp = vec![0; n - 1];
+    unsafe {
+        let rank = rank.as_mut_ptr();
+        let lcp = lcp.as_mut_ptr();
+        for (i, &p) in sa.iter().enumerate() {
+            *rank.add(p) = i;
+        }
+        let mut h: usize = 0;
+        for i in 0..n {
+            h = h.saturating_sub(1);
+            let r = *rank.add(i);
+            if r == 0 {
+                continue;
+            }
+            let j = *sa.get_unchecked(r - 1);
+            while i + h < n && j + h < n && s.get_unchecked(i + h) == s.get_unchecked(j + h) {
+                h += 1;
+            }
+            *lcp.add(r - 1) = h;
+        }
+    }
+    lcp
+}
+
+/// Returns `(start, len)` of a longest substring occurring at least twice (occurrences may
+/// overlap). When no symbol repeats, returns `(0, 0)`.
+///
+/// # Complexity
+/// Time: O(n log n)
+pub fn longest_repeated_substring<T: Ord>(s: &[T]) -> (usize, usize) {
+    let sa = suffix_array(s);
+    let lcp = lcp_array(s, &sa);
+    let mut res = (0, 0);
+    for (i, &h) in lcp.iter().enumerate() {
+        if h > res.1 {
+            res = (sa[i], h);
+        }
+    }
+    res
+}
+
+/// Returns `(start_a, start_b, len)` of a longest common substring of `a` and `b`, where
+/// `a[start_a..start_a + len] == b[start_b..start_b + len]`. When there is no common symbol,
+/// returns `(0, 0, 0)`.
+///
+/// # Complexity
+/// Time: O((n + m) log (n + m))
+pub fn longest_common_substring<T: Ord>(a: &[T], b: &[T]) -> (usize, usize, usize) {
+    let n = a.len();
+    let mut symbols: Vec<&T> = a.iter().chain(b.iter()).collect();
+    symbols.sort_unstable();
+    symbols.dedup();
+    // Symbols are shifted by one so that 0 is a unique separator between `a` and `b`.
+    let mut s = Vec::with_capacity(n + b.len() + 1);
+    for c in a.iter() {
+        s.push(symbols.binary_search(&c).unwrap() + 1);
+    }
+    s.push(0);
+    for c in b.iter() {
+        s.push(symbols.binary_search(&c).unwrap() + 1);
+    }
+
+    let sa = suffix_array(&s);
+    let lcp = lcp_array(&s, &sa);
+    let mut res = (0, 0, 0);
+    for (i, &h) in lcp.iter().enumerate() {
+        let (p, q) = (sa[i], sa[i + 1]);
+        if h > res.2 && (p < n) != (q < n) {
+            res = if p < n {
+                (p, q - n - 1, h)
+            } else {
+                (q, p - n - 1, h)
+            };
+        }
+    }
+    res
+}
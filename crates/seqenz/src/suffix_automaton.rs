@@ -0,0 +1,161 @@
+/// A suffix automaton over symbols in `0..A`, the minimal DFA accepting all suffixes of a string.
+/// State 0 is the initial state, and every distinct substring is the label of exactly one path
+/// from it.
+///
+/// # Complexity
+/// Space: O(nA)
+pub struct SuffixAutomaton<const A: usize> {
+    next: Box<[[u32; A]]>,
+    len: Box<[u32]>,
+    dp: std::cell::OnceCell<Box<[usize]>>,
+}
+
+impl<const A: usize> SuffixAutomaton<A> {
+    /// Creates a new suffix automaton from a slice.
+    ///
+    /// # Complexity
+    /// Time: O(nA)
+    pub fn from_slice(s: &[usize]) -> Self {
+        let n = s.len();
+        let mut next = Vec::with_capacity(2 * n + 1);
+        let mut link = Vec::with_capacity(2 * n + 1);
+        let mut len = Vec::with_capacity(2 * n + 1);
+        next.push([!0u32; A]);
+        link.push(!0usize);
+        len.push(0u32);
+        let mut last = 0;
+        for (i, &c) in s.iter().enumerate() {
+            debug_assert!(c < A, "symbol out of bounds: s[{}]={}, A={}", i, c, A);
+            let cur = next.len();
+            next.push([!0; A]);
+            link.push(0);
+            len.push(len[last] + 1);
+            let mut p = last;
+            while p != !0 && next[p][c] == !0 {
+                next[p][c] = cur as u32;
+                p = link[p];
+            }
+            if p != !0 {
+                let q = next[p][c] as usize;
+                if len[p] + 1 == len[q] {
+                    link[cur] = q;
+                } else {
+                    let clone = next.len();
+                    next.push(next[q]);
+                    link.push(link[q]);
+                    len.push(len[p] + 1);
+                    while p != !0 && next[p][c] == q as u32 {
+                        next[p][c] = clone as u32;
+                        p = link[p];
+                    }
+                    link[q] = clone;
+                    link[cur] = clone;
+                }
+            }
+            last = cur;
+        }
+        Self {
+            next: next.into_boxed_slice(),
+            len: len.into_boxed_slice(),
+            dp: std::cell::OnceCell::new(),
+        }
+    }
+
+    /// Returns whether `t` is a substring of the original string.
+    ///
+    /// # Complexity
+    /// Time: O(|t|)
+    pub fn contains(&self, t: &[usize]) -> bool {
+        let mut state = 0;
+        for &c in t {
+            let next = self.next[state][c];
+            if next == !0 {
+                return false;
+            }
+            state = next as usize;
+        }
+        true
+    }
+
+    /// Returns the number of distinct non-empty substrings.
+    ///
+    /// # Complexity
+    /// Time: initial O(nA), after O(1)
+    pub fn count(&self) -> usize {
+        self.dp()[0]
+    }
+
+    /// Returns the k-th (0-indexed) distinct non-empty substring in lexicographic order. If `k`
+    /// is at least the number of distinct non-empty substrings, returns `None`.
+    ///
+    /// # Complexity
+    /// Time: initial O(nA), after O(|result| A)
+    pub fn kth_substring(&self, mut k: usize) -> Option<Vec<usize>> {
+        let dp = self.dp();
+        if k >= dp[0] {
+            return None;
+        }
+        let mut res = vec![];
+        let mut state = 0;
+        loop {
+            for (c, &next) in self.next[state].iter().enumerate() {
+                if next != !0 {
+                    let cnt = dp[next as usize].saturating_add(1);
+                    if k < cnt {
+                        res.push(c);
+                        state = next as usize;
+                        break;
+                    }
+                    k -= cnt;
+                }
+            }
+            if k == 0 {
+                break;
+            }
+            k -= 1;
+        }
+        Some(res)
+    }
+
+    /// Returns the number of states.
+    ///
+    /// # Complexity
+    /// Time: O(1)
+    #[inline(always)]
+    pub fn num_states(&self) -> usize {
+        self.next.len()
+    }
+
+    /// Returns the number of distinct non-empty paths from each state, visiting states in
+    /// decreasing order of `len` so that every transition target is done first.
+    fn dp(&self) -> &[usize] {
+        self.dp.get_or_init(|| {
+            let m = self.num_states();
+            let max_len = self.len.iter().max().map_or(0, |&l| l as usize);
+            let mut cnt = vec![0usize; max_len + 2];
+            for &l in self.len.iter() {
+                cnt[l as usize + 1] += 1;
+            }
+            for i in 1..cnt.len() {
+                cnt[i] += cnt[i - 1];
+            }
+            let mut order = vec![0; m];
+            for v in 0..m {
+                let l = self.len[v] as usize;
+                order[cnt[l]] = v;
+                cnt[l] += 1;
+            }
+            let mut dp = vec![0usize; m];
+            for &v in order.iter().rev() {
+                let mut sum = 0usize;
+                for &next in self.next[v].iter() {
+                    if next != !0 {
+                        sum = sum.saturating_add(dp[next as usize]).saturating_add(1);
+                    }
+                }
+                dp[v] = sum;
+            }
+            dp.into_boxed_slice()
+        })
+    }
+}